@@ -0,0 +1,94 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::SmallRng, Rng, SeedableRng};
+use std::hint::black_box;
+use str_array::{slice, Str};
+
+fn symbols<const N: usize>(count: usize) -> Vec<Str<N>> {
+    let mut rng = SmallRng::seed_from_u64(N as u64);
+    (0..count)
+        .map(|_| {
+            let mut bytes = [0u8; N];
+            // Few distinct letters, so that neighbours share prefixes like real symbols do.
+            bytes
+                .iter_mut()
+                .for_each(|b| *b = rng.gen_range(b'A'..=b'D'));
+            Str::from_utf8(bytes).unwrap()
+        })
+        .collect()
+}
+
+fn bench_cmp<const N: usize>(c: &mut Criterion) {
+    let symbols = symbols::<N>(4096);
+    let mut group = c.benchmark_group(format!("Str<{N}>"));
+
+    group.bench_function(BenchmarkId::new("cmp", "Str"), |b| {
+        b.iter(|| {
+            symbols
+                .windows(2)
+                .filter(|w| black_box(&w[0]).cmp(black_box(&w[1])).is_lt())
+                .count()
+        })
+    });
+    group.bench_function(BenchmarkId::new("cmp", "str"), |b| {
+        b.iter(|| {
+            symbols
+                .windows(2)
+                .filter(|w| {
+                    black_box(w[0].as_str())
+                        .cmp(black_box(w[1].as_str()))
+                        .is_lt()
+                })
+                .count()
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("eq", "Str"), |b| {
+        b.iter(|| {
+            symbols
+                .windows(2)
+                .filter(|w| black_box(&w[0]) == black_box(&w[1]))
+                .count()
+        })
+    });
+    group.bench_function(BenchmarkId::new("eq", "str"), |b| {
+        b.iter(|| {
+            symbols
+                .windows(2)
+                .filter(|w| black_box(w[0].as_str()) == black_box(w[1].as_str()))
+                .count()
+        })
+    });
+
+    group.bench_function(BenchmarkId::new("sort", "Str"), |b| {
+        b.iter_batched_ref(
+            || symbols.clone(),
+            |v| v.sort_unstable(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    group.bench_function(BenchmarkId::new("sort", "str"), |b| {
+        b.iter_batched_ref(
+            || symbols.clone(),
+            |v| v.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str())),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    group.bench_function(BenchmarkId::new("sort", "radix"), |b| {
+        b.iter_batched_ref(
+            || symbols.clone(),
+            |v| slice::sort_radix(v),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_cmp::<4>,
+    bench_cmp::<8>,
+    bench_cmp::<12>,
+    bench_cmp::<16>
+);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+use str_array::Str;
+
+fn ascii<const N: usize>() -> [u8; N] {
+    let mut bytes = [0u8; N];
+    bytes
+        .iter_mut()
+        .enumerate()
+        .for_each(|(i, b)| *b = b'a' + (i % 26) as u8);
+    bytes
+}
+
+fn bench_from_utf8<const N: usize>(c: &mut Criterion) {
+    let bytes = ascii::<N>();
+    let s = core::str::from_utf8(&bytes).unwrap();
+    // The same length, but with a 2-byte char at the end so the ASCII fast path fails late.
+    let mut utf8 = bytes;
+    utf8[N - 2..].copy_from_slice("é".as_bytes());
+    let mut group = c.benchmark_group(format!("Str<{N}>"));
+
+    group.bench_function(BenchmarkId::new("from_utf8", "const"), |b| {
+        b.iter(|| Str::from_utf8(black_box(bytes)))
+    });
+    group.bench_function(BenchmarkId::new("from_utf8", "fast"), |b| {
+        b.iter(|| Str::from_utf8_fast(black_box(bytes)))
+    });
+    group.bench_function(BenchmarkId::new("from_utf8 non-ASCII", "const"), |b| {
+        b.iter(|| Str::from_utf8(black_box(utf8)))
+    });
+    group.bench_function(BenchmarkId::new("from_utf8 non-ASCII", "fast"), |b| {
+        b.iter(|| Str::from_utf8_fast(black_box(utf8)))
+    });
+
+    group.bench_function(BenchmarkId::new("try_new", "const"), |b| {
+        b.iter(|| Str::<N>::try_new(black_box(s)))
+    });
+    group.bench_function(BenchmarkId::new("try_new", "fast"), |b| {
+        b.iter(|| Str::<N>::try_new_fast(black_box(s)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_from_utf8::<16>,
+    bench_from_utf8::<256>,
+    bench_from_utf8::<4096>
+);
+criterion_main!(benches);
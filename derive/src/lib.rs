@@ -0,0 +1,536 @@
+//! Derive macros for [`str-array`](https://docs.rs/str-array).
+//!
+//! Use them through the `derive` feature of `str-array`, which re-exports them.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Fields, LitByte, LitChar,
+    LitInt, LitStr,
+};
+
+/// Derives a fixed-layout text record from a struct of fixed-width fields.
+///
+/// Every field is laid out back to back, in declaration order. The total width is computed at
+/// compile time and exposed as an inherent `WIDTH` constant, along with:
+///
+/// * `fn parse(bytes: &[u8; WIDTH]) -> Result<Self, RecordError>`
+/// * `fn try_to_bytes(&self) -> Result<[u8; WIDTH], RecordError>`
+/// * `fn to_bytes(&self) -> [u8; WIDTH]`, which panics if a numeric field does not fit.
+///
+/// Fields are either a [`FixedField`] (e.g. `Str<N>`), or an integer marked as numeric with
+/// `#[record(width = W)]`. Numeric fields accept these extra attributes:
+///
+/// * `pad = '0'` - the byte filling the unused part of the field (defaults to `'0'` for right
+///   aligned fields and `' '` for left aligned ones). It cannot be a sign or a digit, except
+///   `'0'` on the left of the digits.
+/// * `align = "right"` or `align = "left"` - which side the digits are on (defaults to right).
+///
+/// Any field can also be preceded by filler bytes with `#[record(gap = K)]`, which are written
+/// as spaces and ignored when parsing.
+///
+/// [`FixedField`]: https://docs.rs/str-array/latest/str_array/record/trait.FixedField.html
+///
+/// # Examples
+///
+/// ```
+/// use str_array::{FixedRecord, Str};
+///
+/// #[derive(FixedRecord, Debug, PartialEq)]
+/// struct Trade {
+///     side: Str<4>,
+///     symbol: Str<6>,
+///     #[record(width = 8)]
+///     quantity: u32,
+///     #[record(gap = 1, width = 7, pad = ' ', align = "left")]
+///     price: i64,
+/// }
+///
+/// assert_eq!(Trade::WIDTH, 26);
+///
+/// let trade = Trade::parse(b"BUY AAPL  00000100 -1250  ").unwrap();
+/// assert_eq!(trade.side, "BUY ");
+/// assert_eq!(trade.quantity, 100);
+/// assert_eq!(trade.price, -1250);
+/// assert_eq!(&trade.to_bytes(), b"BUY AAPL  00000100 -1250  ");
+///
+/// let err = Trade::parse(b"BUY AAPL  000X0100 -1250  ").unwrap_err();
+/// assert_eq!(err.field(), "quantity");
+/// assert_eq!(err.offset(), 10);
+/// ```
+///
+/// Numbers that do not fit their width are reported when serializing:
+///
+/// ```
+/// # use str_array::FixedRecord;
+/// #[derive(FixedRecord)]
+/// struct Count(#[record(width = 2)] u16);
+///
+/// assert_eq!(&Count(42).to_bytes(), b"42");
+/// assert!(Count(420).try_to_bytes().is_err());
+/// ```
+///
+/// Left aligned numbers are padded with spaces, so trailing zeros are kept:
+///
+/// ```
+/// # use str_array::FixedRecord;
+/// #[derive(FixedRecord, Debug, PartialEq)]
+/// struct Quantity(#[record(width = 6, align = "left")] u32);
+///
+/// assert_eq!(&Quantity(100).to_bytes(), b"100   ");
+/// assert_eq!(Quantity::parse(b"100   "), Ok(Quantity(100)));
+/// assert!(Quantity::parse(b"+100  ").is_err());
+/// ```
+///
+/// ```compile_fail
+/// # use str_array::FixedRecord;
+/// #[derive(FixedRecord)]
+/// struct Quantity(#[record(width = 6, pad = '0', align = "left")] u32);
+/// ```
+#[proc_macro_derive(FixedRecord, attributes(record))]
+pub fn derive_fixed_record(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fixed_record(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derives a mapping between a fieldless enum and fixed-width [`Str<N>`] codes.
+///
+/// Every variant must carry a `#[code = "..."]` attribute. All codes must have the same length in
+/// bytes, which becomes `N`, and must be unique; both are checked at compile time. The derive
+/// generates:
+///
+/// * `const fn as_code(&self) -> Str<N>`
+/// * `From<Enum> for Str<N>`
+/// * `TryFrom<Str<N>>` and `TryFrom<&str>` for the enum, failing with [`UnknownCode`].
+///
+/// [`Str<N>`]: https://docs.rs/str-array/latest/str_array/struct.Str.html
+/// [`UnknownCode`]: https://docs.rs/str-array/latest/str_array/struct.UnknownCode.html
+///
+/// # Examples
+///
+/// ```
+/// use str_array::{Str, StrCode};
+///
+/// #[derive(StrCode, Debug, PartialEq)]
+/// enum Side {
+///     #[code = "BUY "]
+///     Buy,
+///     #[code = "SELL"]
+///     Sell,
+/// }
+///
+/// const SELL: Str<4> = Side::Sell.as_code();
+/// assert_eq!(SELL, "SELL");
+/// assert_eq!(Side::try_from("BUY "), Ok(Side::Buy));
+/// assert_eq!(Side::try_from(SELL), Ok(Side::Sell));
+/// assert!(Side::try_from("BUY").is_err());
+/// ```
+///
+/// Codes of different lengths are rejected:
+///
+/// ```compile_fail
+/// # use str_array::StrCode;
+/// #[derive(StrCode)]
+/// enum Side {
+///     #[code = "BUY"]
+///     Buy,
+///     #[code = "SELL"]
+///     Sell,
+/// }
+/// ```
+///
+/// And so are duplicate codes:
+///
+/// ```compile_fail
+/// # use str_array::StrCode;
+/// #[derive(StrCode)]
+/// enum Currency {
+///     #[code = "USD"]
+///     Usd,
+///     #[code = "USD"]
+///     Dollar,
+/// }
+/// ```
+#[proc_macro_derive(StrCode, attributes(code))]
+pub fn derive_str_code(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    str_code(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn str_code(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "StrCode does not support generic enums",
+        ));
+    }
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "StrCode can only be derived for enums",
+            ))
+        }
+    };
+    if variants.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "StrCode requires at least one variant",
+        ));
+    }
+
+    let mut codes: Vec<(&syn::Ident, LitStr)> = Vec::with_capacity(variants.len());
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(
+                variant.fields.span(),
+                "StrCode variants cannot have fields",
+            ));
+        }
+        let mut code = None;
+        for attr in variant.attrs.iter().filter(|a| a.path().is_ident("code")) {
+            let value = &attr.meta.require_name_value()?.value;
+            match value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit),
+                    ..
+                }) if code.is_none() => code = Some(lit.clone()),
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(_),
+                    ..
+                }) => return Err(Error::new(attr.span(), "duplicate `code`")),
+                _ => return Err(Error::new(value.span(), "expected a string literal")),
+            }
+        }
+        let code = code.ok_or_else(|| {
+            Error::new(
+                variant.ident.span(),
+                "missing `#[code = \"...\"]` attribute",
+            )
+        })?;
+        if let Some((_, first)) = codes.first() {
+            if first.value().len() != code.value().len() {
+                return Err(Error::new(
+                    code.span(),
+                    format!(
+                        "code has {} bytes, but the first code has {}",
+                        code.value().len(),
+                        first.value().len()
+                    ),
+                ));
+            }
+        }
+        if let Some((other, _)) = codes.iter().find(|(_, c)| c.value() == code.value()) {
+            return Err(Error::new(
+                code.span(),
+                format!("code is already used by `{}`", other),
+            ));
+        }
+        codes.push((&variant.ident, code));
+    }
+
+    let len = codes[0].1.value().len();
+    let str_ty = quote!(::str_array::Str<#len>);
+    let idents: Vec<_> = codes.iter().map(|(ident, _)| *ident).collect();
+    let lits: Vec<_> = codes.iter().map(|(_, lit)| lit).collect();
+    let byte_lits = codes
+        .iter()
+        .map(|(_, lit)| syn::LitByteStr::new(lit.value().as_bytes(), lit.span()));
+
+    Ok(quote! {
+        impl #name {
+            /// Returns the fixed-width code of this variant.
+            #[must_use]
+            pub const fn as_code(&self) -> #str_ty {
+                match self {
+                    #(
+                        Self::#idents => {
+                            const CODE: #str_ty = match ::str_array::Str::try_new(#lits) {
+                                ::core::result::Result::Ok(code) => code,
+                                ::core::result::Result::Err(_) => ::core::unreachable!(),
+                            };
+                            CODE
+                        }
+                    )*
+                }
+            }
+        }
+
+        impl ::core::convert::From<#name> for #str_ty {
+            #[inline]
+            fn from(v: #name) -> Self {
+                v.as_code()
+            }
+        }
+
+        impl ::core::convert::TryFrom<#str_ty> for #name {
+            type Error = ::str_array::UnknownCode;
+
+            #[inline]
+            fn try_from(code: #str_ty) -> ::core::result::Result<Self, Self::Error> {
+                match code.as_bytes() {
+                    #(#byte_lits => ::core::result::Result::Ok(Self::#idents),)*
+                    _ => ::core::result::Result::Err(::str_array::UnknownCode),
+                }
+            }
+        }
+
+        impl<'a> ::core::convert::TryFrom<&'a str> for #name {
+            type Error = ::str_array::UnknownCode;
+
+            #[inline]
+            fn try_from(code: &'a str) -> ::core::result::Result<Self, Self::Error> {
+                match code {
+                    #(#lits => ::core::result::Result::Ok(Self::#idents),)*
+                    _ => ::core::result::Result::Err(::str_array::UnknownCode),
+                }
+            }
+        }
+    })
+}
+
+enum FieldKind {
+    Fixed,
+    Numeric {
+        width: LitInt,
+        pad: u8,
+        align: TokenStream2,
+    },
+}
+
+struct RecordField {
+    member: TokenStream2,
+    name: String,
+    ty: syn::Type,
+    gap: usize,
+    kind: FieldKind,
+}
+
+fn parse_field(index: usize, field: &syn::Field) -> syn::Result<RecordField> {
+    let mut width = None;
+    let mut pad = None;
+    let mut align = None;
+    let mut gap = 0;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("record")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("width") {
+                width = Some(meta.value()?.parse::<LitInt>()?);
+            } else if meta.path.is_ident("gap") {
+                gap = meta.value()?.parse::<LitInt>()?.base10_parse()?;
+            } else if meta.path.is_ident("pad") {
+                let value = meta.value()?;
+                let byte = if value.peek(LitByte) {
+                    value.parse::<LitByte>()?.value()
+                } else {
+                    let lit = value.parse::<LitChar>()?;
+                    u8::try_from(lit.value())
+                        .ok()
+                        .filter(u8::is_ascii)
+                        .ok_or_else(|| Error::new(lit.span(), "padding must be ASCII"))?
+                };
+                pad = Some((byte, meta.path.span()));
+            } else if meta.path.is_ident("align") {
+                let lit = meta.value()?.parse::<LitStr>()?;
+                align = Some(match lit.value().as_str() {
+                    "left" => true,
+                    "right" => false,
+                    _ => return Err(Error::new(lit.span(), "expected \"left\" or \"right\"")),
+                });
+            } else {
+                return Err(meta.error("unsupported record attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let kind = match width {
+        Some(width) => {
+            let left = align == Some(true);
+            let pad = match pad {
+                // Padding that looks like part of a number would be parsed as one. Zeros are
+                // only safe before the digits, where they do not change the value.
+                Some((byte, span))
+                    if matches!(byte, b'+' | b'-')
+                        || byte.is_ascii_digit() && (left || byte != b'0') =>
+                {
+                    return Err(Error::new(
+                        span,
+                        "`pad` must not be a digit or a sign, except '0' on right aligned fields",
+                    ));
+                }
+                Some((byte, _)) => byte,
+                None if left => b' ',
+                None => b'0',
+            };
+            FieldKind::Numeric {
+                width,
+                pad,
+                align: if left {
+                    quote!(::str_array::record::Align::Left)
+                } else {
+                    quote!(::str_array::record::Align::Right)
+                },
+            }
+        }
+        None => {
+            if let Some((_, span)) = pad {
+                return Err(Error::new(span, "`pad` requires a numeric `width`"));
+            }
+            if align.is_some() {
+                return Err(Error::new(
+                    field.span(),
+                    "`align` requires a numeric `width`",
+                ));
+            }
+            FieldKind::Fixed
+        }
+    };
+    let (member, name) = match &field.ident {
+        Some(ident) => (quote!(#ident), ident.to_string()),
+        None => {
+            let index = syn::Index::from(index);
+            (quote!(#index), index.index.to_string())
+        }
+    };
+    Ok(RecordField {
+        member,
+        name,
+        ty: field.ty.clone(),
+        gap,
+        kind,
+    })
+}
+
+fn fixed_record(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "FixedRecord does not support generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new(
+                Span::call_site(),
+                "FixedRecord can only be derived for structs",
+            ))
+        }
+    };
+    let fields = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| parse_field(i, f))
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let widths: Vec<_> = fields
+        .iter()
+        .map(|f| {
+            let ty = &f.ty;
+            match &f.kind {
+                FieldKind::Fixed => quote!(<#ty as ::str_array::record::FixedField>::WIDTH),
+                FieldKind::Numeric { width, .. } => quote!(#width),
+            }
+        })
+        .collect();
+    let gaps: Vec<_> = fields.iter().map(|f| f.gap).collect();
+
+    let bindings: Vec<_> = (0..fields.len())
+        .map(|i| format_ident!("field{}", i))
+        .collect();
+    let reads = fields.iter().zip(&widths).zip(&bindings).map(|((f, width), binding)| {
+        let (ty, field_name, gap) = (&f.ty, &f.name, f.gap);
+        let read = match &f.kind {
+            FieldKind::Fixed => quote! {
+                <#ty as ::str_array::record::FixedField>::read_field(bytes)
+            },
+            FieldKind::Numeric { pad, align, .. } => quote! {
+                <#ty as ::str_array::record::NumericField>::read_numeric(bytes, #pad, #align)
+            },
+        };
+        quote! {
+            offset += #gap;
+            let #binding = {
+                let bytes = &bytes[offset..offset + #width];
+                #read.map_err(|e| ::str_array::record::RecordError::new(#field_name, offset, e))?
+            };
+            offset += #width;
+        }
+    });
+    let construct = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(_) => {
+                let members = fields.iter().map(|f| &f.member);
+                quote!(Self { #(#members: #bindings),* })
+            }
+            Fields::Unnamed(_) => quote!(Self(#(#bindings),*)),
+            Fields::Unit => quote!(Self),
+        },
+        _ => unreachable!(),
+    };
+    let writes = fields.iter().zip(&widths).map(|(f, width)| {
+        let (ty, member, field_name, gap) = (&f.ty, &f.member, &f.name, f.gap);
+        let write = match &f.kind {
+            FieldKind::Fixed => quote! {
+                <#ty as ::str_array::record::FixedField>::write_field(&self.#member, out);
+            },
+            FieldKind::Numeric { pad, align, .. } => quote! {
+                <#ty as ::str_array::record::NumericField>::write_numeric(&self.#member, out, #pad, #align)
+                    .map_err(|e| ::str_array::record::RecordError::new(#field_name, offset, e))?;
+            },
+        };
+        quote! {
+            offset += #gap;
+            {
+                let out = &mut bytes[offset..offset + #width];
+                #write
+            }
+            offset += #width;
+        }
+    });
+
+    Ok(quote! {
+        impl #name {
+            /// The width in bytes of the serialized record.
+            pub const WIDTH: usize = 0 #(+ #gaps + #widths)*;
+
+            /// Parses the record from its fixed-width representation.
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            pub fn parse(bytes: &[u8; #name::WIDTH]) -> ::core::result::Result<Self, ::str_array::record::RecordError> {
+                let mut offset = 0usize;
+                #(#reads)*
+                ::core::result::Result::Ok(#construct)
+            }
+
+            /// Serializes the record into its fixed-width representation.
+            #[allow(unused_assignments, unused_mut, unused_variables)]
+            pub fn try_to_bytes(&self) -> ::core::result::Result<[u8; #name::WIDTH], ::str_array::record::RecordError> {
+                let mut bytes = [b' '; #name::WIDTH];
+                let mut offset = 0usize;
+                #(#writes)*
+                ::core::result::Result::Ok(bytes)
+            }
+
+            /// Serializes the record into its fixed-width representation.
+            ///
+            /// # Panics
+            ///
+            /// Panics if a numeric field does not fit in its width.
+            #[track_caller]
+            pub fn to_bytes(&self) -> [u8; #name::WIDTH] {
+                match self.try_to_bytes() {
+                    ::core::result::Result::Ok(bytes) => bytes,
+                    ::core::result::Result::Err(e) => ::core::panic!("{}", e),
+                }
+            }
+        }
+    })
+}
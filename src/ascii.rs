@@ -0,0 +1,599 @@
+use crate::{InvalidLength, Str};
+use core::{cmp::Ordering, fmt, hash, ops, str};
+
+/// An ASCII character, i.e. a byte in `0..=127`.
+///
+/// This is the element type of [`AsciiStr`], and only allows writing bytes that keep it valid.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AsciiChar(u8);
+
+impl AsciiChar {
+    /// Creates an `AsciiChar` from a byte, returning `None` if it is not ASCII.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiChar;
+    /// assert_eq!(AsciiChar::new(b'a').unwrap().as_char(), 'a');
+    /// assert!(AsciiChar::new(0xE9).is_none());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn new(b: u8) -> Option<Self> {
+        if b.is_ascii() {
+            Some(Self(b))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an `AsciiChar` from a `char`, returning `None` if it is not ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn from_char(c: char) -> Option<Self> {
+        if c.is_ascii() {
+            Some(Self(c as u8))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the character as a byte.
+    #[inline]
+    #[must_use]
+    pub const fn as_u8(self) -> u8 {
+        self.0
+    }
+
+    /// Returns the character as a `char`.
+    #[inline]
+    #[must_use]
+    pub const fn as_char(self) -> char {
+        self.0 as char
+    }
+
+    /// Returns the uppercase equivalent of this character, or itself if it is not a letter.
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_uppercase(self) -> Self {
+        Self(self.0.to_ascii_uppercase())
+    }
+
+    /// Returns the lowercase equivalent of this character, or itself if it is not a letter.
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_lowercase(self) -> Self {
+        Self(self.0.to_ascii_lowercase())
+    }
+}
+
+impl From<AsciiChar> for u8 {
+    #[inline]
+    fn from(c: AsciiChar) -> Self {
+        c.0
+    }
+}
+
+impl From<AsciiChar> for char {
+    #[inline]
+    fn from(c: AsciiChar) -> Self {
+        c.as_char()
+    }
+}
+
+impl PartialEq<u8> for AsciiChar {
+    #[inline]
+    fn eq(&self, other: &u8) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<char> for AsciiChar {
+    #[inline]
+    fn eq(&self, other: &char) -> bool {
+        self.as_char() == *other
+    }
+}
+
+impl fmt::Display for AsciiChar {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.as_char(), f)
+    }
+}
+
+impl fmt::Debug for AsciiChar {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_char(), f)
+    }
+}
+
+/// The error returned when bytes that should be ASCII are not.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct NotAscii {
+    index: usize,
+}
+
+impl NotAscii {
+    /// Returns the index of the first non-ASCII byte.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NotAscii {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected ASCII, found a non-ASCII byte at {}",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotAscii {}
+
+/// The error returned by [`AsciiStr::try_new`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum AsciiStrError {
+    /// The string does not have exactly `N` bytes.
+    InvalidLength(InvalidLength),
+    /// The string is not ASCII.
+    NotAscii(NotAscii),
+}
+
+impl fmt::Display for AsciiStrError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AsciiStrError::InvalidLength(e) => fmt::Display::fmt(e, f),
+            AsciiStrError::NotAscii(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsciiStrError {}
+
+impl From<InvalidLength> for AsciiStrError {
+    #[inline]
+    fn from(e: InvalidLength) -> Self {
+        AsciiStrError::InvalidLength(e)
+    }
+}
+
+impl From<NotAscii> for AsciiStrError {
+    #[inline]
+    fn from(e: NotAscii) -> Self {
+        AsciiStrError::NotAscii(e)
+    }
+}
+
+/// A stack based string of exactly `N` ASCII characters.
+///
+/// Every byte is a character, so indexing by byte is indexing by character, every index is a
+/// character boundary, and case conversion never changes the length.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{AsciiChar, AsciiStr, Str};
+/// const USD: AsciiStr<3> = match AsciiStr::from_ascii(*b"usd") {
+///     Ok(s) => s.to_ascii_uppercase(),
+///     Err(_) => panic!(),
+/// };
+/// assert_eq!(USD, "USD");
+///
+/// let mut code = USD;
+/// code[2] = AsciiChar::new(b'S').unwrap();
+/// assert_eq!(code[0], 'U');
+///
+/// let s: Str<3> = code.into();
+/// assert_eq!(s, "USS");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct AsciiStr<const N: usize> {
+    v: [u8; N],
+}
+
+impl<const N: usize> AsciiStr<N> {
+    /// Converts an array of bytes to an `AsciiStr`, checking that they are all ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotAscii`] with the index of the first byte above `127`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiStr;
+    /// assert!(AsciiStr::from_ascii(*b"EUR").is_ok());
+    ///
+    /// let err = AsciiStr::from_ascii(*b"caf\xC3\xA9").unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 3);
+    /// ```
+    #[inline]
+    pub const fn from_ascii(v: [u8; N]) -> Result<Self, NotAscii> {
+        let mut i = 0;
+        while i < N {
+            if !v[i].is_ascii() {
+                return Err(NotAscii { index: i });
+            }
+            i += 1;
+        }
+        Ok(Self { v })
+    }
+
+    /// Converts an array of bytes to an `AsciiStr` without checking that they are ASCII.
+    ///
+    /// # Safety
+    ///
+    /// Every byte must be in `0..=127`.
+    #[inline]
+    #[must_use]
+    pub const unsafe fn from_ascii_unchecked(v: [u8; N]) -> Self {
+        debug_assert!(v.is_ascii());
+        Self { v }
+    }
+
+    /// Copies a `&str` into a new `AsciiStr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsciiStrError`] if the string's length is not exactly `N`, or if it is not
+    /// ASCII.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiStr;
+    /// let s: AsciiStr<4> = AsciiStr::try_new("SELL").unwrap();
+    /// assert_eq!(s, "SELL");
+    ///
+    /// assert!(AsciiStr::<4>::try_new("SEL").is_err());
+    /// assert!(AsciiStr::<4>::try_new("VÉN").is_err());
+    /// ```
+    #[inline]
+    pub const fn try_new(s: &str) -> Result<Self, AsciiStrError> {
+        match Str::try_new(s) {
+            Ok(s) => match Self::from_str_array(s) {
+                Ok(s) => Ok(s),
+                Err(e) => Err(AsciiStrError::NotAscii(e)),
+            },
+            Err(e) => Err(AsciiStrError::InvalidLength(e)),
+        }
+    }
+
+    /// Converts a [`Str`] into an `AsciiStr`, checking that it is ASCII.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotAscii`] with the index of the first byte above `127`.
+    #[inline]
+    pub const fn from_str_array(s: Str<N>) -> Result<Self, NotAscii> {
+        Self::from_ascii(s.into_bytes())
+    }
+
+    /// Converts this `AsciiStr` into a [`Str`], which is always valid.
+    #[inline]
+    #[must_use]
+    pub const fn into_str_array(self) -> Str<N> {
+        // Safety: ASCII is valid UTF-8.
+        unsafe { Str::from_utf8_unchecked(self.v) }
+    }
+
+    /// Extracts a string slice containing the entire `AsciiStr`.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // Safety: ASCII is valid UTF-8.
+        unsafe { str::from_utf8_unchecked(&self.v) }
+    }
+
+    /// Returns a byte slice of this `AsciiStr`'s contents.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.v
+    }
+
+    /// Converts an `AsciiStr` into a byte array.
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.v
+    }
+
+    /// Returns a slice of the characters of this `AsciiStr`.
+    #[inline]
+    #[must_use]
+    pub const fn as_chars(&self) -> &[AsciiChar; N] {
+        // Safety: `AsciiChar` is a `repr(transparent)` `u8`, and every byte is ASCII.
+        unsafe { &*(&self.v as *const [u8; N] as *const [AsciiChar; N]) }
+    }
+
+    /// Returns a mutable slice of the characters of this `AsciiStr`.
+    ///
+    /// Writing through it can only store ASCII characters, so the `AsciiStr` stays valid.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut_chars(&mut self) -> &mut [AsciiChar; N] {
+        // Safety: `AsciiChar` is a `repr(transparent)` `u8`, and every byte is ASCII.
+        unsafe { &mut *(&mut self.v as *mut [u8; N] as *mut [AsciiChar; N]) }
+    }
+
+    /// Returns the length of this `AsciiStr`, which is both its number of bytes and of
+    /// characters.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if this `AsciiStr` has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the character at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiStr;
+    /// let s: AsciiStr<3> = AsciiStr::try_new("GBP").unwrap();
+    /// assert_eq!(s.get(1).unwrap().as_u8(), b'B');
+    /// assert_eq!(s.get(3), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn get(&self, index: usize) -> Option<AsciiChar> {
+        if index < N {
+            Some(AsciiChar(self.v[index]))
+        } else {
+            None
+        }
+    }
+
+    /// Replaces the character at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{AsciiChar, AsciiStr};
+    /// let mut s: AsciiStr<3> = AsciiStr::try_new("GBP").unwrap();
+    /// s.set(0, AsciiChar::new(b'X').unwrap());
+    /// assert_eq!(s, "XBP");
+    /// ```
+    #[inline]
+    #[track_caller]
+    pub const fn set(&mut self, index: usize, c: AsciiChar) {
+        self.v[index] = c.0;
+    }
+
+    /// Returns a copy of this `AsciiStr` with every letter in uppercase.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiStr;
+    /// const CODE: AsciiStr<3> = match AsciiStr::from_ascii(*b"eur") {
+    ///     Ok(s) => s.to_ascii_uppercase(),
+    ///     Err(_) => panic!(),
+    /// };
+    /// assert_eq!(CODE, "EUR");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_uppercase(mut self) -> Self {
+        self.make_ascii_uppercase();
+        self
+    }
+
+    /// Returns a copy of this `AsciiStr` with every letter in lowercase.
+    #[inline]
+    #[must_use]
+    pub const fn to_ascii_lowercase(mut self) -> Self {
+        self.make_ascii_lowercase();
+        self
+    }
+
+    /// Converts every letter of this `AsciiStr` to uppercase, in place.
+    #[inline]
+    pub const fn make_ascii_uppercase(&mut self) {
+        let mut i = 0;
+        while i < N {
+            self.v[i] = self.v[i].to_ascii_uppercase();
+            i += 1;
+        }
+    }
+
+    /// Converts every letter of this `AsciiStr` to lowercase, in place.
+    #[inline]
+    pub const fn make_ascii_lowercase(&mut self) {
+        let mut i = 0;
+        while i < N {
+            self.v[i] = self.v[i].to_ascii_lowercase();
+            i += 1;
+        }
+    }
+
+    /// Checks that two `AsciiStr`s are equal, ignoring the case of letters.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::AsciiStr;
+    /// let a: AsciiStr<3> = AsciiStr::try_new("usd").unwrap();
+    /// let b: AsciiStr<3> = AsciiStr::try_new("USD").unwrap();
+    /// assert!(a.eq_ignore_ascii_case(&b));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn eq_ignore_ascii_case(&self, other: &Self) -> bool {
+        self.v.eq_ignore_ascii_case(&other.v)
+    }
+}
+
+impl<const N: usize> TryFrom<Str<N>> for AsciiStr<N> {
+    type Error = NotAscii;
+    #[inline]
+    fn try_from(v: Str<N>) -> Result<Self, Self::Error> {
+        Self::from_str_array(v)
+    }
+}
+
+impl<const N: usize> TryFrom<[u8; N]> for AsciiStr<N> {
+    type Error = NotAscii;
+    #[inline]
+    fn try_from(v: [u8; N]) -> Result<Self, Self::Error> {
+        Self::from_ascii(v)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for AsciiStr<N> {
+    type Error = AsciiStrError;
+    /// Try to convert a `&str` into an [`AsciiStr<N>`].
+    ///
+    /// The result will fail if the string's length is not exactly `N`, or if it is not ASCII.
+    #[inline]
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const N: usize> From<AsciiStr<N>> for Str<N> {
+    #[inline]
+    fn from(v: AsciiStr<N>) -> Self {
+        v.into_str_array()
+    }
+}
+
+impl<const N: usize> AsRef<str> for AsciiStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for AsciiStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.v
+    }
+}
+
+impl<const N: usize> ops::Deref for AsciiStr<N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> ops::Index<usize> for AsciiStr<N> {
+    type Output = AsciiChar;
+
+    #[inline]
+    fn index(&self, index: usize) -> &AsciiChar {
+        &self.as_chars()[index]
+    }
+}
+
+impl<const N: usize> ops::IndexMut<usize> for AsciiStr<N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut AsciiChar {
+        &mut self.as_mut_chars()[index]
+    }
+}
+
+impl<const N: usize> PartialEq<str> for AsciiStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for AsciiStr<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> PartialEq<Str<N>> for AsciiStr<N> {
+    #[inline]
+    fn eq(&self, other: &Str<N>) -> bool {
+        self.v == other.v
+    }
+}
+
+impl<const N: usize> PartialEq<AsciiStr<N>> for Str<N> {
+    #[inline]
+    fn eq(&self, other: &AsciiStr<N>) -> bool {
+        self.v == other.v
+    }
+}
+
+impl<const N: usize> PartialOrd for AsciiStr<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for AsciiStr<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        <str as Ord>::cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl<const N: usize> hash::Hash for AsciiStr<N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+impl<const N: usize> fmt::Display for AsciiStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for AsciiStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,321 @@
+use crate::Str;
+use core::{fmt, mem::ManuallyDrop};
+
+#[cfg(loom)]
+use loom::{
+    hint,
+    sync::atomic::{fence, AtomicU32, AtomicU8, Ordering},
+};
+
+#[cfg(not(loom))]
+use core::{
+    hint,
+    sync::atomic::{fence, AtomicU32, AtomicU8, Ordering},
+};
+
+// The widest native atomic integer, that short strings are packed into.
+#[cfg(all(target_has_atomic = "64", not(loom)))]
+use core::sync::atomic::AtomicU64 as AtomicWord;
+#[cfg(all(target_has_atomic = "64", loom))]
+use loom::sync::atomic::AtomicU64 as AtomicWord;
+#[cfg(target_has_atomic = "64")]
+type Word = u64;
+
+#[cfg(not(target_has_atomic = "64"))]
+use AtomicU32 as AtomicWord;
+#[cfg(not(target_has_atomic = "64"))]
+type Word = u32;
+
+/// A [`Str<N>`] that can be shared between threads, to publish a small label from one thread
+/// and read it from many without a `Mutex`.
+///
+/// When `N` fits in the widest native atomic integer, 8 bytes on most targets and 4 on those
+/// without 64-bit atomics, the string is packed into it and every operation is one atomic
+/// instruction. Longer strings are stored as atomic bytes guarded by a seqlock: readers never
+/// block and retry if a write overlapped their read, while writers take turns.
+///
+/// Loads have `Acquire` and stores `Release` semantics, so whatever a thread wrote before
+/// storing a string is visible to the threads that load it.
+///
+/// # Examples
+///
+/// ```
+/// # use std::{sync::Arc, thread};
+/// # use str_array::{AtomicStr, Str};
+/// let state = Arc::new(AtomicStr::new(Str::<8>::try_new("starting").unwrap()));
+///
+/// let writer = {
+///     let state = Arc::clone(&state);
+///     thread::spawn(move || state.store(Str::try_new("running!").unwrap()))
+/// };
+/// let label = state.load();
+/// assert!(label == "starting" || label == "running!");
+///
+/// writer.join().unwrap();
+/// assert_eq!(state.load(), "running!");
+/// ```
+///
+/// The packed integer and the seqlock share their storage, which takes the larger of the two:
+/// the integer, or a 4-byte sequence number followed by the `N` bytes.
+///
+/// ```
+/// # use str_array::AtomicStr;
+/// assert_eq!(size_of::<AtomicStr<4>>(), 8);
+/// assert_eq!(size_of::<AtomicStr<8>>(), 16);
+/// assert_eq!(size_of::<AtomicStr<12>>(), 16);
+/// ```
+pub struct AtomicStr<const N: usize> {
+    repr: Repr<N>,
+}
+
+// `packed` when `AtomicStr::NATIVE`, and `seqlock` otherwise.
+union Repr<const N: usize> {
+    packed: ManuallyDrop<AtomicWord>,
+    seqlock: ManuallyDrop<SeqLock<N>>,
+}
+
+struct SeqLock<const N: usize> {
+    // Odd while a write is in progress.
+    seq: AtomicU32,
+    data: [AtomicU8; N],
+}
+
+impl<const N: usize> AtomicStr<N> {
+    const NATIVE: bool = N <= size_of::<Word>();
+
+    /// Creates a new `AtomicStr` holding `s`.
+    #[cfg(not(loom))]
+    #[inline]
+    #[must_use]
+    pub const fn new(s: Str<N>) -> Self {
+        if Self::NATIVE {
+            let packed = ManuallyDrop::new(AtomicWord::new(pack(&s)));
+            return Self {
+                repr: Repr { packed },
+            };
+        }
+        let mut data = [const { AtomicU8::new(0) }; N];
+        let mut i = 0;
+        while i < N {
+            data[i] = AtomicU8::new(s.v[i]);
+            i += 1;
+        }
+        let seqlock = ManuallyDrop::new(SeqLock {
+            seq: AtomicU32::new(0),
+            data,
+        });
+        Self {
+            repr: Repr { seqlock },
+        }
+    }
+
+    /// Creates a new `AtomicStr` holding `s`.
+    #[cfg(loom)]
+    #[must_use]
+    pub fn new(s: Str<N>) -> Self {
+        if Self::NATIVE {
+            let packed = ManuallyDrop::new(AtomicWord::new(pack(&s)));
+            return Self {
+                repr: Repr { packed },
+            };
+        }
+        let seqlock = ManuallyDrop::new(SeqLock {
+            seq: AtomicU32::new(0),
+            data: core::array::from_fn(|i| AtomicU8::new(s.v[i])),
+        });
+        Self {
+            repr: Repr { seqlock },
+        }
+    }
+
+    #[inline(always)]
+    fn packed(&self) -> &AtomicWord {
+        debug_assert!(Self::NATIVE);
+        // Safety: `new` initializes `packed` when `NATIVE`, which only calls this then.
+        unsafe { &self.repr.packed }
+    }
+
+    #[inline(always)]
+    fn seqlock(&self) -> &SeqLock<N> {
+        debug_assert!(!Self::NATIVE);
+        // Safety: `new` initializes `seqlock` unless `NATIVE`, which only calls this then.
+        unsafe { &self.repr.seqlock }
+    }
+
+    /// Returns the current string.
+    #[inline]
+    pub fn load(&self) -> Str<N> {
+        if Self::NATIVE {
+            return unpack(self.packed().load(Ordering::Acquire));
+        }
+        let seqlock = self.seqlock();
+        loop {
+            let seq = seqlock.seq.load(Ordering::Acquire);
+            if !seq.is_multiple_of(2) {
+                hint::spin_loop();
+                continue;
+            }
+            let v = seqlock.read_data();
+            // Orders the reads of the bytes before the check of the sequence number.
+            fence(Ordering::Acquire);
+            if seqlock.seq.load(Ordering::Relaxed) == seq {
+                // Safety: no write overlapped the read, so the bytes are a stored `Str`.
+                return unsafe { Str::from_utf8_unchecked(v) };
+            }
+        }
+    }
+
+    /// Replaces the current string with `s`.
+    #[inline]
+    pub fn store(&self, s: Str<N>) {
+        if Self::NATIVE {
+            return self.packed().store(pack(&s), Ordering::Release);
+        }
+        let seqlock = self.seqlock();
+        let seq = seqlock.lock();
+        seqlock.write_data(&s);
+        seqlock.unlock(seq);
+    }
+
+    /// Replaces the current string with `s`, and returns the previous one.
+    #[inline]
+    pub fn swap(&self, s: Str<N>) -> Str<N> {
+        if Self::NATIVE {
+            return unpack(self.packed().swap(pack(&s), Ordering::AcqRel));
+        }
+        let seqlock = self.seqlock();
+        let seq = seqlock.lock();
+        let previous = seqlock.read_data();
+        seqlock.write_data(&s);
+        seqlock.unlock(seq);
+        // Safety: we held the lock, so the bytes are a stored `Str`.
+        unsafe { Str::from_utf8_unchecked(previous) }
+    }
+
+    /// Replaces the current string with `new` if it is equal to `current`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current string if it is not equal to `current`, and leaves it unchanged.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{AtomicStr, Str};
+    /// let idle = Str::<12>::try_new("idle        ").unwrap();
+    /// let busy = Str::<12>::try_new("busy        ").unwrap();
+    /// let state = AtomicStr::new(idle);
+    ///
+    /// assert_eq!(state.compare_exchange(idle, busy), Ok(idle));
+    /// assert_eq!(state.compare_exchange(idle, busy), Err(busy));
+    /// ```
+    #[inline]
+    pub fn compare_exchange(&self, current: Str<N>, new: Str<N>) -> Result<Str<N>, Str<N>> {
+        if Self::NATIVE {
+            return self
+                .packed()
+                .compare_exchange(
+                    pack(&current),
+                    pack(&new),
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                )
+                .map(unpack)
+                .map_err(unpack);
+        }
+        let seqlock = self.seqlock();
+        let seq = seqlock.lock();
+        let previous = seqlock.read_data();
+        let result = if previous == current.v {
+            seqlock.write_data(&new);
+            Ok(current)
+        } else {
+            // Safety: we held the lock, so the bytes are a stored `Str`.
+            Err(unsafe { Str::from_utf8_unchecked(previous) })
+        };
+        seqlock.unlock(seq);
+        result
+    }
+
+    /// Consumes the `AtomicStr` and returns the string.
+    #[inline]
+    pub fn into_inner(self) -> Str<N> {
+        self.load()
+    }
+}
+
+impl<const N: usize> SeqLock<N> {
+    /// Takes the seqlock for writing, and returns the even sequence number it had.
+    fn lock(&self) -> u32 {
+        loop {
+            let seq = self.seq.load(Ordering::Relaxed);
+            if seq.is_multiple_of(2)
+                && self
+                    .seq
+                    .compare_exchange_weak(seq, seq + 1, Ordering::Acquire, Ordering::Relaxed)
+                    .is_ok()
+            {
+                // Orders the odd sequence number before the writes of the bytes.
+                fence(Ordering::Release);
+                return seq;
+            }
+            hint::spin_loop();
+        }
+    }
+
+    fn unlock(&self, seq: u32) {
+        self.seq.store(seq.wrapping_add(2), Ordering::Release);
+    }
+
+    fn read_data(&self) -> [u8; N] {
+        let mut v = [0u8; N];
+        for (v, b) in v.iter_mut().zip(&self.data) {
+            *v = b.load(Ordering::Relaxed);
+        }
+        v
+    }
+
+    fn write_data(&self, s: &Str<N>) {
+        for (b, v) in self.data.iter().zip(s.as_bytes()) {
+            b.store(*v, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Packs a string that fits in a `Word`.
+#[inline(always)]
+const fn pack<const N: usize>(s: &Str<N>) -> Word {
+    let mut bytes = [0u8; size_of::<Word>()];
+    let mut i = 0;
+    while i < N {
+        bytes[i] = s.v[i];
+        i += 1;
+    }
+    Word::from_le_bytes(bytes)
+}
+
+/// Unpacks a string packed by [`pack`].
+#[inline(always)]
+fn unpack<const N: usize>(bits: Word) -> Str<N> {
+    let bytes = bits.to_le_bytes();
+    let mut v = [0u8; N];
+    v.copy_from_slice(&bytes[..N]);
+    // Safety: only packed strings are stored.
+    unsafe { Str::from_utf8_unchecked(v) }
+}
+
+impl<const N: usize> From<Str<N>> for AtomicStr<N> {
+    #[inline]
+    fn from(s: Str<N>) -> Self {
+        Self::new(s)
+    }
+}
+
+impl<const N: usize> fmt::Debug for AtomicStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(), f)
+    }
+}
@@ -0,0 +1,299 @@
+use crate::{InvalidLength, Str};
+use core::{fmt, ops, str};
+
+/// A stack based byte string of exactly `N` bytes, that is usually but not always UTF-8.
+///
+/// Where [`Str<N>`] rejects invalid UTF-8 up front, `BStr` keeps the raw bytes of fields like
+/// legacy feeds that may contain Latin-1 or garbage, so they can be validated lazily with
+/// [`to_str`](BStr::to_str) or converted into a [`Str<N>`].
+///
+/// `Display` replaces invalid UTF-8 with `U+FFFD REPLACEMENT CHARACTER`, and `Debug` escapes it
+/// as `\xNN`.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{BStr, Str};
+/// let field = BStr::new(*b"caf\xE9");
+/// assert!(field.to_str().is_err());
+/// assert_eq!(field.to_string(), "caf\u{FFFD}");
+/// assert_eq!(format!("{:?}", field), r#""caf\xE9""#);
+///
+/// let field = BStr::new(*b"cafe");
+/// let s: Str<4> = field.try_into().unwrap();
+/// assert_eq!(s, "cafe");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct BStr<const N: usize> {
+    v: [u8; N],
+}
+
+impl<const N: usize> BStr<N> {
+    /// Wraps an array of bytes.
+    #[inline]
+    #[must_use]
+    pub const fn new(v: [u8; N]) -> Self {
+        Self { v }
+    }
+
+    /// Copies a byte slice of exactly `N` bytes into a new `BStr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the slice is not exactly `N` bytes long.
+    #[inline]
+    pub const fn try_new(bytes: &[u8]) -> Result<Self, InvalidLength> {
+        if bytes.len() != N {
+            return Err(InvalidLength {
+                expected: N,
+                actual: bytes.len(),
+            });
+        }
+        let mut v = [0u8; N];
+        // We use `while` because `copy_from_slice` is not const fn yet.
+        let mut i = 0;
+        while i < N {
+            v[i] = bytes[i];
+            i += 1;
+        }
+        Ok(Self { v })
+    }
+
+    /// Returns a byte slice of this `BStr`'s contents.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        &self.v
+    }
+
+    /// Returns a mutable byte slice of this `BStr`'s contents.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.v
+    }
+
+    /// Converts a `BStr` into a byte array.
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const fn into_bytes(self) -> [u8; N] {
+        self.v
+    }
+
+    /// Returns the length of this `BStr` in bytes, which is always `N`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if this `BStr` has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns the contents as a string slice if they are valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`](str::Utf8Error) if the bytes are not valid UTF-8.
+    #[inline]
+    pub const fn to_str(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(&self.v)
+    }
+
+    /// Writes the contents into `buf` with invalid UTF-8 replaced by `U+FFFD REPLACEMENT
+    /// CHARACTER`, and returns the written string slice.
+    ///
+    /// A replacement character takes 3 bytes, so a buffer of `3 * N` bytes always fits the whole
+    /// string. If `buf` is smaller, the output is truncated at a character boundary.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::BStr;
+    /// let field = BStr::new(*b"\xC9t\xE9");
+    ///
+    /// let mut buf = [0u8; 9];
+    /// assert_eq!(field.to_str_lossy(&mut buf), "\u{FFFD}t\u{FFFD}");
+    ///
+    /// let mut small = [0u8; 5];
+    /// assert_eq!(field.to_str_lossy(&mut small), "\u{FFFD}t");
+    /// ```
+    #[inline]
+    pub fn to_str_lossy<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        const REPLACEMENT: &str = "\u{FFFD}";
+        let mut len = 0;
+        'chunks: for chunk in self.v.utf8_chunks() {
+            for c in chunk.valid().chars() {
+                let Some(dst) = buf.get_mut(len..len + c.len_utf8()) else {
+                    break 'chunks;
+                };
+                c.encode_utf8(dst);
+                len += c.len_utf8();
+            }
+            if !chunk.invalid().is_empty() {
+                let Some(dst) = buf.get_mut(len..len + REPLACEMENT.len()) else {
+                    break;
+                };
+                dst.copy_from_slice(REPLACEMENT.as_bytes());
+                len += REPLACEMENT.len();
+            }
+        }
+        // Safety: we only wrote whole UTF-8 encoded chars.
+        unsafe { str::from_utf8_unchecked(&buf[..len]) }
+    }
+}
+
+impl<const N: usize> Default for BStr<N> {
+    #[inline]
+    fn default() -> Self {
+        Self { v: [0; N] }
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for BStr<N> {
+    #[inline]
+    fn from(v: [u8; N]) -> Self {
+        Self::new(v)
+    }
+}
+
+impl<const N: usize> From<Str<N>> for BStr<N> {
+    #[inline]
+    fn from(v: Str<N>) -> Self {
+        Self::new(v.into_bytes())
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for BStr<N> {
+    type Error = InvalidLength;
+    /// Try to convert a byte slice into a [`BStr<N>`].
+    ///
+    /// The result will fail if the slice's length is not exactly `N`.
+    #[inline]
+    fn try_from(v: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const N: usize> TryFrom<BStr<N>> for Str<N> {
+    type Error = str::Utf8Error;
+    /// Try to convert a [`BStr<N>`] into a [`Str<N>`].
+    ///
+    /// The result will fail if the bytes are not valid UTF-8.
+    #[inline]
+    fn try_from(v: BStr<N>) -> Result<Self, Self::Error> {
+        Str::from_utf8(v.v)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for BStr<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.v
+    }
+}
+
+impl<const N: usize> AsMut<[u8]> for BStr<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.v
+    }
+}
+
+impl<const N: usize> ops::Deref for BStr<N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.v
+    }
+}
+
+impl<const N: usize> ops::DerefMut for BStr<N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.v
+    }
+}
+
+impl<const N: usize> PartialEq<[u8]> for BStr<N> {
+    #[inline]
+    fn eq(&self, other: &[u8]) -> bool {
+        self.v == other
+    }
+}
+
+impl<const N: usize> PartialEq<&[u8]> for BStr<N> {
+    #[inline]
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.v == *other
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for BStr<N> {
+    #[inline]
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.v == *other
+    }
+}
+
+impl<const N: usize> PartialEq<str> for BStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.v == other.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for BStr<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.v == other.as_bytes()
+    }
+}
+
+impl<const N: usize> fmt::Display for BStr<N> {
+    /// Writes the bytes as UTF-8, with `U+FFFD` for invalid sequences, padded like a `str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::BStr;
+    /// let s = BStr::new(*b"ab\xFF");
+    /// assert_eq!(format!("[{s:>5}]"), "[  ab\u{FFFD}]");
+    /// assert_eq!(format!("[{s:.2}]"), "[ab]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Ok(s) = self.to_str() {
+            return f.pad(s);
+        }
+        let chars = self.v.utf8_chunks().flat_map(|chunk| {
+            let replacement = (!chunk.invalid().is_empty()).then_some('\u{FFFD}');
+            chunk.valid().chars().chain(replacement)
+        });
+        crate::pad_chars(f, chars)
+    }
+}
+
+impl<const N: usize> fmt::Debug for BStr<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for chunk in self.v.utf8_chunks() {
+            crate::write_debug_chars(chunk.valid(), |s| f.write_str(s))?;
+            for b in chunk.invalid() {
+                write!(f, "\\x{:02X}", b)?;
+            }
+        }
+        f.write_char('"')
+    }
+}
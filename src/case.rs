@@ -0,0 +1,352 @@
+//! Case-insensitive comparison of strings, for keys like HTTP header names or ticker symbols.
+//!
+//! [`CaseInsensitive`] wraps an owned string and compares, orders and hashes it ignoring case,
+//! while keeping the original casing for display. [`UncasedStr`] is its borrowed counterpart:
+//! since `str`'s own `Hash` and `Eq` are case-sensitive, maps keyed by `CaseInsensitive` are
+//! looked up with an `&UncasedStr` rather than an `&str`.
+//!
+//! The folding rule is picked by a [`CaseFold`] type parameter: [`Ascii`] by default, or
+//! [`Unicode`] for a table-free approximation of Unicode simple case folding.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::collections::HashMap;
+//! use str_array::{case::{CaseInsensitive, UncasedStr}, Str};
+//!
+//! let mut prices: HashMap<CaseInsensitive<Str<4>>, u32> = HashMap::new();
+//! prices.insert(CaseInsensitive::new(Str::try_new("Aapl").unwrap()), 190);
+//!
+//! assert_eq!(prices.get(UncasedStr::new("AAPL")), Some(&190));
+//! assert_eq!(prices.get(UncasedStr::new("aapl")), Some(&190));
+//!
+//! // The original casing is kept.
+//! let key = prices.keys().next().unwrap();
+//! assert_eq!(key.to_string(), "Aapl");
+//! ```
+
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A rule for comparing strings while ignoring case.
+///
+/// This trait is sealed, use [`Ascii`] or [`Unicode`].
+pub trait CaseFold: sealed::Sealed {
+    #[doc(hidden)]
+    fn eq(a: &str, b: &str) -> bool;
+    #[doc(hidden)]
+    fn cmp(a: &str, b: &str) -> Ordering;
+    #[doc(hidden)]
+    fn hash<H: Hasher>(s: &str, state: &mut H);
+}
+
+/// Ignores the case of ASCII letters only.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ascii;
+
+/// Ignores the case of every Unicode letter that has a single-character case mapping.
+///
+/// Each character is folded to the lowercase of its uppercase, keeping it as is when either
+/// mapping expands to several characters. This matches Unicode simple case folding for most
+/// scripts (e.g. `'ς'`, `'σ'` and `'Σ'` are equal) without shipping the folding tables.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Unicode;
+
+impl sealed::Sealed for Ascii {}
+impl sealed::Sealed for Unicode {}
+
+impl CaseFold for Ascii {
+    #[inline]
+    fn eq(a: &str, b: &str) -> bool {
+        a.eq_ignore_ascii_case(b)
+    }
+
+    #[inline]
+    fn cmp(a: &str, b: &str) -> Ordering {
+        let a = a.bytes().map(|b| b.to_ascii_lowercase());
+        let b = b.bytes().map(|b| b.to_ascii_lowercase());
+        a.cmp(b)
+    }
+
+    fn hash<H: Hasher>(s: &str, state: &mut H) {
+        let mut buf = [0u8; 32];
+        for chunk in s.as_bytes().chunks(buf.len()) {
+            let buf = &mut buf[..chunk.len()];
+            buf.copy_from_slice(chunk);
+            buf.make_ascii_lowercase();
+            state.write(buf);
+        }
+        // Like `str`, end with a byte that cannot appear in UTF-8 so prefixes hash differently.
+        state.write_u8(0xff);
+    }
+}
+
+fn fold(c: char) -> char {
+    fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Some(c),
+            _ => None,
+        }
+    }
+    let upper = single(c.to_uppercase()).unwrap_or(c);
+    single(upper.to_lowercase()).unwrap_or(upper)
+}
+
+impl CaseFold for Unicode {
+    #[inline]
+    fn eq(a: &str, b: &str) -> bool {
+        a.chars().map(fold).eq(b.chars().map(fold))
+    }
+
+    #[inline]
+    fn cmp(a: &str, b: &str) -> Ordering {
+        a.chars().map(fold).cmp(b.chars().map(fold))
+    }
+
+    fn hash<H: Hasher>(s: &str, state: &mut H) {
+        for c in s.chars() {
+            state.write_u32(fold(c) as u32);
+        }
+        state.write_u8(0xff);
+    }
+}
+
+/// An owned string that compares, orders and hashes ignoring case.
+///
+/// The original casing is kept, and used by `Display` and `Debug`. See the [module
+/// documentation](self) for looking up maps keyed by `CaseInsensitive`.
+///
+/// # Examples
+///
+/// ```
+/// use str_array::{case::{CaseInsensitive, Unicode}, Str};
+///
+/// let a = CaseInsensitive::<_>::new(Str::<4>::try_new("MSFT").unwrap());
+/// let b = CaseInsensitive::new(Str::<4>::try_new("msft").unwrap());
+/// assert_eq!(a, b);
+/// assert_eq!(a, *"Msft");
+///
+/// let a = CaseInsensitive::<Str<5>, Unicode>::new(Str::try_new("ÉTÉ").unwrap());
+/// let b = CaseInsensitive::<Str<5>, Unicode>::new(Str::try_new("été").unwrap());
+/// assert_eq!(a, b);
+/// ```
+#[derive(Copy, Clone, Default)]
+#[repr(transparent)]
+pub struct CaseInsensitive<S, F = Ascii> {
+    inner: S,
+    fold: PhantomData<F>,
+}
+
+impl<S, F> CaseInsensitive<S, F> {
+    /// Wraps `inner` so it is compared ignoring case.
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: S) -> Self {
+        Self {
+            inner,
+            fold: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the wrapped string, with its original casing.
+    #[inline]
+    #[must_use]
+    pub const fn get(&self) -> &S {
+        &self.inner
+    }
+
+    /// Unwraps the string, with its original casing.
+    #[inline]
+    #[must_use]
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> CaseInsensitive<S, F> {
+    /// Returns the wrapped string as a case-insensitive string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_uncased(&self) -> &UncasedStr<F> {
+        UncasedStr::new(self.inner.as_ref())
+    }
+}
+
+impl<S, F> From<S> for CaseInsensitive<S, F> {
+    #[inline]
+    fn from(inner: S) -> Self {
+        Self::new(inner)
+    }
+}
+
+impl<S: AsRef<str>, F> AsRef<str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.inner.as_ref()
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> Borrow<UncasedStr<F>> for CaseInsensitive<S, F> {
+    #[inline]
+    fn borrow(&self) -> &UncasedStr<F> {
+        self.as_uncased()
+    }
+}
+
+impl<S: AsRef<str>, T: AsRef<str>, F: CaseFold> PartialEq<CaseInsensitive<T, F>>
+    for CaseInsensitive<S, F>
+{
+    #[inline]
+    fn eq(&self, other: &CaseInsensitive<T, F>) -> bool {
+        F::eq(self.inner.as_ref(), other.inner.as_ref())
+    }
+}
+impl<S: AsRef<str>, F: CaseFold> Eq for CaseInsensitive<S, F> {}
+
+impl<S: AsRef<str>, F: CaseFold> PartialEq<str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        F::eq(self.inner.as_ref(), other)
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> PartialEq<&str> for CaseInsensitive<S, F> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        F::eq(self.inner.as_ref(), other)
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> PartialOrd for CaseInsensitive<S, F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> Ord for CaseInsensitive<S, F> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        F::cmp(self.inner.as_ref(), other.inner.as_ref())
+    }
+}
+
+impl<S: AsRef<str>, F: CaseFold> Hash for CaseInsensitive<S, F> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F::hash(self.inner.as_ref(), state)
+    }
+}
+
+impl<S: fmt::Display, F> fmt::Display for CaseInsensitive<S, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for CaseInsensitive<S, F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+/// A string slice that compares, orders and hashes ignoring case.
+///
+/// This is the borrowed form of [`CaseInsensitive`], used to look up maps keyed by it.
+#[repr(transparent)]
+pub struct UncasedStr<F = Ascii> {
+    fold: PhantomData<F>,
+    s: str,
+}
+
+impl<F> UncasedStr<F> {
+    /// Wraps a string slice so it is compared ignoring case.
+    #[inline]
+    #[must_use]
+    pub fn new(s: &str) -> &Self {
+        // Safety: `UncasedStr` is a `repr(transparent)` `str`.
+        unsafe { &*(s as *const str as *const Self) }
+    }
+
+    /// Returns the string slice, with its original casing.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.s
+    }
+}
+
+impl<'a, F> From<&'a str> for &'a UncasedStr<F> {
+    #[inline]
+    fn from(s: &'a str) -> Self {
+        UncasedStr::new(s)
+    }
+}
+
+impl<F> AsRef<str> for UncasedStr<F> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.s
+    }
+}
+
+impl<F: CaseFold> PartialEq for UncasedStr<F> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        F::eq(&self.s, &other.s)
+    }
+}
+impl<F: CaseFold> Eq for UncasedStr<F> {}
+
+impl<F: CaseFold> PartialEq<str> for UncasedStr<F> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        F::eq(&self.s, other)
+    }
+}
+
+impl<F: CaseFold> PartialOrd for UncasedStr<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<F: CaseFold> Ord for UncasedStr<F> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        F::cmp(&self.s, &other.s)
+    }
+}
+
+impl<F: CaseFold> Hash for UncasedStr<F> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        F::hash(&self.s, state)
+    }
+}
+
+impl<F> fmt::Display for UncasedStr<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.s, f)
+    }
+}
+
+impl<F> fmt::Debug for UncasedStr<F> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.s, f)
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{InvalidLength, Str};
+use core::{cmp::Ordering, fmt, hash, ops, str};
+
+/// The error returned when a string does not fit in a [`CharStr`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum CharStrError {
+    /// The string does not have exactly `M` characters.
+    InvalidCharCount {
+        /// The number of characters required.
+        expected: usize,
+        /// The number of characters in the string.
+        actual: usize,
+    },
+    /// The string has `M` characters, but more than `N` bytes.
+    CapacityExceeded {
+        /// The byte capacity of the `CharStr`.
+        capacity: usize,
+        /// The length of the string in bytes.
+        actual: usize,
+    },
+}
+
+impl fmt::Display for CharStrError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CharStrError::InvalidCharCount { expected, actual } => write!(
+                f,
+                "Expected string with {} chars, but got {}",
+                expected, actual
+            ),
+            CharStrError::CapacityExceeded { capacity, actual } => write!(
+                f,
+                "Expected string with at most {} bytes, but got {}",
+                capacity, actual
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CharStrError {}
+
+/// A stack based string of exactly `M` [`char`]s, stored in at most `N` bytes.
+///
+/// Where [`Str<N>`] fixes the length in bytes, `CharStr` fixes the number of characters, which
+/// is what protocols like SMS or UI labels constrain. `N` must be between `M` and `4 * M`; use
+/// `4 * M` to accept any `M` characters.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{CharStr, Str};
+/// let label: CharStr<5, 20> = CharStr::try_new("héllo").unwrap();
+/// assert_eq!(label.char_count(), 5);
+/// assert_eq!(label.len(), 6);
+/// assert_eq!(label.char_at(1), Some('é'));
+///
+/// let bytes: Str<6> = label.try_into().unwrap();
+/// assert_eq!(bytes, "héllo");
+///
+/// assert!(CharStr::<5, 20>::try_new("hello!").is_err());
+/// ```
+#[derive(Copy, Clone)]
+pub struct CharStr<const M: usize, const N: usize> {
+    v: [u8; N],
+    len: usize,
+}
+
+impl<const M: usize, const N: usize> CharStr<M, N> {
+    /// Copies a `&str` of exactly `M` characters into a new `CharStr`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CharStrError`] if the string does not have exactly `M` characters, or if it is
+    /// longer than `N` bytes.
+    #[inline]
+    pub const fn try_new(s: &str) -> Result<Self, CharStrError> {
+        const {
+            assert!(
+                M <= N && N <= 4 * M,
+                "CharStr<M, N> requires M <= N <= 4 * M"
+            )
+        };
+        let bytes = s.as_bytes();
+        let actual = count_chars(bytes);
+        if actual != M {
+            return Err(CharStrError::InvalidCharCount {
+                expected: M,
+                actual,
+            });
+        }
+        if bytes.len() > N {
+            return Err(CharStrError::CapacityExceeded {
+                capacity: N,
+                actual: bytes.len(),
+            });
+        }
+        let mut v = [0u8; N];
+        // We use `while` because `copy_from_slice` is not const fn yet.
+        let mut i = 0;
+        while i < bytes.len() {
+            v[i] = bytes[i];
+            i += 1;
+        }
+        Ok(Self {
+            v,
+            len: bytes.len(),
+        })
+    }
+
+    /// Extracts a string slice containing the entire `CharStr`.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        // Safety: the first `len` bytes were copied from a `str`.
+        unsafe { str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Returns a byte slice of this `CharStr`'s contents.
+    #[inline]
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        self.v.split_at(self.len).0
+    }
+
+    /// Returns the length of this `CharStr` in bytes, which is between `M` and `N`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this `CharStr` has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        M == 0
+    }
+
+    /// Returns the number of characters, which is always `M`.
+    #[inline]
+    #[must_use]
+    pub const fn char_count(&self) -> usize {
+        M
+    }
+
+    /// Returns `true` if every character is ASCII.
+    #[inline]
+    #[must_use]
+    pub const fn is_ascii(&self) -> bool {
+        self.len == M
+    }
+
+    /// Returns the character at `index`, or `None` if `index >= M`.
+    ///
+    /// This is O(1) when the string is ASCII, and O(`index`) otherwise.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::CharStr;
+    /// let s: CharStr<3, 12> = CharStr::try_new("a💖b").unwrap();
+    /// assert_eq!(s.char_at(1), Some('💖'));
+    /// assert_eq!(s.char_at(2), Some('b'));
+    /// assert_eq!(s.char_at(3), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn char_at(&self, index: usize) -> Option<char> {
+        if index >= M {
+            None
+        } else if self.is_ascii() {
+            Some(char::from(self.v[index]))
+        } else {
+            self.as_str().chars().nth(index)
+        }
+    }
+
+    /// Converts this `CharStr` into a [`Str<K>`] if its length in bytes is exactly `K`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string's length is not exactly `K` bytes.
+    #[inline]
+    pub const fn to_str_array<const K: usize>(&self) -> Result<Str<K>, InvalidLength> {
+        Str::try_new(self.as_str())
+    }
+}
+
+const fn count_chars(bytes: &[u8]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        // Count every byte that is not a continuation byte.
+        if (bytes[i] as i8) >= -0x40 {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+impl<const M: usize, const N: usize> TryFrom<&str> for CharStr<M, N> {
+    type Error = CharStrError;
+    /// Try to convert a `&str` into a [`CharStr<M, N>`].
+    ///
+    /// The result will fail if the string does not have exactly `M` characters, or if it is
+    /// longer than `N` bytes.
+    #[inline]
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const M: usize, const N: usize, const K: usize> TryFrom<CharStr<M, N>> for Str<K> {
+    type Error = InvalidLength;
+    /// Try to convert a [`CharStr<M, N>`] into a [`Str<K>`].
+    ///
+    /// The result will fail if the string's length is not exactly `K` bytes.
+    #[inline]
+    fn try_from(v: CharStr<M, N>) -> Result<Self, Self::Error> {
+        v.to_str_array()
+    }
+}
+
+impl<const M: usize, const N: usize> AsRef<str> for CharStr<M, N> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const M: usize, const N: usize> AsRef<[u8]> for CharStr<M, N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const M: usize, const N: usize> ops::Deref for CharStr<M, N> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const M: usize, const N: usize> PartialEq for CharStr<M, N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+impl<const M: usize, const N: usize> Eq for CharStr<M, N> {}
+
+impl<const M: usize, const N: usize> PartialEq<str> for CharStr<M, N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const M: usize, const N: usize> PartialEq<&str> for CharStr<M, N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const M: usize, const N: usize> PartialOrd for CharStr<M, N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const M: usize, const N: usize> Ord for CharStr<M, N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        <str as Ord>::cmp(self.as_str(), other.as_str())
+    }
+}
+
+impl<const M: usize, const N: usize> hash::Hash for CharStr<M, N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.as_str().hash(hasher)
+    }
+}
+
+impl<const M: usize, const N: usize> fmt::Display for CharStr<M, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const M: usize, const N: usize> fmt::Debug for CharStr<M, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
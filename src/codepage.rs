@@ -0,0 +1,474 @@
+//! Transcoding between [`Str<N>`] and single-byte legacy encodings, for fixed-width fields of
+//! mainframe and older Windows systems.
+//!
+//! Each byte of a [`Codepage`] decodes to exactly one `char`, so a `K`-byte field decodes to `K`
+//! characters: either into a [`Str<N>`] when its UTF-8 length is known, or into a
+//! [`CharStr<K, N>`] with room for the expansion. Latin-1 and the EBCDIC code pages only decode
+//! to characters below `U+0100`, which take at most 2 bytes in UTF-8, and Windows-1252 to
+//! characters that take at most 3.
+//!
+//! # Examples
+//!
+//! ```
+//! use str_array::{codepage::{CP037, WINDOWS_1252}, CharStr, Str};
+//!
+//! // "HELLO" in EBCDIC.
+//! let field = [0xC8, 0xC5, 0xD3, 0xD3, 0xD6];
+//! let s: Str<5> = CP037.decode(&field).unwrap();
+//! assert_eq!(s, "HELLO");
+//! assert_eq!(CP037.encode::<5>(&s), Ok(field));
+//!
+//! let price: CharStr<4, 12> = WINDOWS_1252.decode_chars(b"\x8012.").unwrap();
+//! assert_eq!(price, "€12.");
+//! assert_eq!(WINDOWS_1252.encode::<4>(&price), Ok(*b"\x8012."));
+//! ```
+
+use crate::{CharStr, CharStrError, InvalidLength, Str};
+use core::{fmt, str};
+
+/// The characters that the code page cannot encode, reported by [`Codepage::encode`].
+///
+/// The error lists the first [`Unrepresentable::MAX_LISTED`] of them with their byte index,
+/// and counts them all. [`Codepage::unrepresentable`] lists every one of them, but allocating
+/// or borrowing the string would make this error neither `Copy` nor `'static`.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct Unrepresentable {
+    listed: [(usize, char); Unrepresentable::MAX_LISTED],
+    count: usize,
+}
+
+impl Unrepresentable {
+    /// The maximum number of characters listed by [`chars`](Self::chars).
+    pub const MAX_LISTED: usize = 4;
+
+    /// Returns the byte index of the first unrepresentable character.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        self.listed[0].0
+    }
+
+    /// Returns the first unrepresentable character.
+    #[inline]
+    #[must_use]
+    pub const fn char(&self) -> char {
+        self.listed[0].1
+    }
+
+    /// Returns the byte index and value of the first unrepresentable characters, up to
+    /// [`MAX_LISTED`](Self::MAX_LISTED) of them.
+    #[inline]
+    #[must_use]
+    pub fn chars(&self) -> &[(usize, char)] {
+        &self.listed[..self.count.min(Self::MAX_LISTED)]
+    }
+
+    /// Returns the number of unrepresentable characters in the string, including those not
+    /// listed by [`chars`](Self::chars).
+    ///
+    /// Use [`Codepage::unrepresentable`] to list all of them.
+    #[inline]
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+}
+
+impl fmt::Display for Unrepresentable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Cannot encode ")?;
+        for (n, (index, ch)) in self.chars().iter().enumerate() {
+            if n > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{:?} at {}", ch, index)?;
+        }
+        let unlisted = self.count - self.chars().len();
+        if unlisted > 0 {
+            write!(f, " and {} more chars", unlisted)?;
+        }
+        f.write_str(" in the code page")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Unrepresentable {}
+
+/// The error returned by [`Codepage::encode`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum EncodeError {
+    /// The string does not have exactly `K` characters, so it would not encode to `K` bytes.
+    InvalidCharCount {
+        /// The number of characters required.
+        expected: usize,
+        /// The number of characters in the string.
+        actual: usize,
+    },
+    /// The string has characters that the code page cannot encode.
+    Unrepresentable(Unrepresentable),
+}
+
+impl fmt::Display for EncodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::InvalidCharCount { expected, actual } => write!(
+                f,
+                "Expected string with {} chars, but got {}",
+                expected, actual
+            ),
+            EncodeError::Unrepresentable(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
+impl From<Unrepresentable> for EncodeError {
+    #[inline]
+    fn from(e: Unrepresentable) -> Self {
+        EncodeError::Unrepresentable(e)
+    }
+}
+
+/// The marker for chars below `U+0100` that a code page cannot encode.
+const NONE: u16 = u16::MAX;
+
+/// A single-byte encoding, where each of the 256 bytes stands for one `char`.
+#[derive(Clone, Debug)]
+pub struct Codepage {
+    name: &'static str,
+    decode: [char; 256],
+    // The byte encoding each char below `U+0100`, or `NONE`. Other chars are searched in
+    // `decode`, which only Windows-1252 needs.
+    encode_low: [u16; 256],
+}
+
+/// ISO-8859-1, where each byte is the code point of the same value.
+pub static LATIN_1: Codepage = Codepage::new("ISO-8859-1", &{
+    let mut table = [0u16; 256];
+    let mut i = 0;
+    while i < table.len() {
+        table[i] = i as u16;
+        i += 1;
+    }
+    table
+});
+
+/// Windows-1252, the Western European Windows code page.
+///
+/// The five bytes it leaves undefined decode to the C1 control of the same value, like web
+/// browsers do, so decoding never fails.
+pub static WINDOWS_1252: Codepage = Codepage::new("Windows-1252", &WINDOWS_1252_TABLE);
+
+/// EBCDIC code page 037, used by IBM mainframes in the US and Canada.
+pub static CP037: Codepage = Codepage::new("IBM037", &CP037_TABLE);
+
+/// EBCDIC code page 500, the international variant of code page 037.
+pub static CP500: Codepage = Codepage::new("IBM500", &CP500_TABLE);
+
+impl Codepage {
+    const fn new(name: &'static str, table: &[u16; 256]) -> Self {
+        let mut decode = ['\0'; 256];
+        let mut encode_low = [NONE; 256];
+        let mut i = 0;
+        while i < 256 {
+            decode[i] = match char::from_u32(table[i] as u32) {
+                Some(c) => c,
+                None => panic!("code page table has a surrogate"),
+            };
+            if table[i] < 256 {
+                encode_low[table[i] as usize] = i as u16;
+            }
+            i += 1;
+        }
+        Self {
+            name,
+            decode,
+            encode_low,
+        }
+    }
+
+    /// Returns the name of the code page, e.g. `"IBM037"`.
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the `char` that `byte` stands for.
+    #[inline]
+    #[must_use]
+    pub const fn decode_char(&self, byte: u8) -> char {
+        self.decode[byte as usize]
+    }
+
+    /// Returns the byte that stands for `c`, or `None` if the code page cannot encode it.
+    #[inline]
+    #[must_use]
+    pub fn encode_char(&self, c: char) -> Option<u8> {
+        match self.encode_low.get(c as usize) {
+            Some(&NONE) => None,
+            Some(&b) => Some(b as u8),
+            None => self.decode.iter().position(|&d| d == c).map(|b| b as u8),
+        }
+    }
+
+    /// Returns the length in bytes of `bytes` once decoded to UTF-8.
+    #[inline]
+    #[must_use]
+    pub fn decoded_len(&self, bytes: &[u8]) -> usize {
+        bytes.iter().map(|&b| self.decode_char(b).len_utf8()).sum()
+    }
+
+    /// Decodes `bytes` into a [`Str<N>`], if its UTF-8 length is exactly `N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] with the [decoded length](Self::decoded_len) if it is not `N`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{codepage::LATIN_1, Str};
+    /// let s: Str<5> = LATIN_1.decode(b"caf\xE9").unwrap();
+    /// assert_eq!(s, "café");
+    /// assert!(LATIN_1.decode::<4>(b"caf\xE9").is_err());
+    /// ```
+    #[inline]
+    pub fn decode<const N: usize>(&self, bytes: &[u8]) -> Result<Str<N>, InvalidLength> {
+        let actual = self.decoded_len(bytes);
+        if actual != N {
+            return Err(InvalidLength {
+                expected: N,
+                actual,
+            });
+        }
+        let mut v = [0u8; N];
+        self.decode_into(bytes, &mut v);
+        // Safety: `decode_into` wrote `N` bytes of encoded chars.
+        Ok(unsafe { Str::from_utf8_unchecked(v) })
+    }
+
+    /// Decodes a `K`-byte field into a [`CharStr<K, N>`] of its `K` characters.
+    ///
+    /// Use `N = 2 * K` for Latin-1 and the EBCDIC code pages, and `N = 3 * K` for Windows-1252,
+    /// for this to always succeed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CharStrError::CapacityExceeded`] if the decoded string is longer than `N`
+    /// bytes.
+    #[inline]
+    pub fn decode_chars<const K: usize, const N: usize>(
+        &self,
+        bytes: &[u8; K],
+    ) -> Result<CharStr<K, N>, CharStrError> {
+        let actual = self.decoded_len(bytes);
+        if actual > N {
+            return Err(CharStrError::CapacityExceeded {
+                capacity: N,
+                actual,
+            });
+        }
+        let mut v = [0u8; N];
+        self.decode_into(bytes, &mut v);
+        // Safety: `decode_into` wrote `actual` bytes of encoded chars.
+        CharStr::try_new(unsafe { str::from_utf8_unchecked(&v[..actual]) })
+    }
+
+    /// Decodes `bytes` to UTF-8 at the start of `out`, which must be long enough.
+    fn decode_into(&self, bytes: &[u8], out: &mut [u8]) {
+        let mut i = 0;
+        for &b in bytes {
+            let c = self.decode_char(b);
+            c.encode_utf8(&mut out[i..]);
+            i += c.len_utf8();
+        }
+    }
+
+    /// Encodes a string of exactly `K` characters into `K` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EncodeError::InvalidCharCount`] if the string does not have exactly `K`
+    /// characters, and [`EncodeError::Unrepresentable`] listing the characters the code page
+    /// cannot encode otherwise.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::codepage::{EncodeError, CP500};
+    /// assert_eq!(CP500.encode::<3>("A1!"), Ok([0xC1, 0xF1, 0x4F]));
+    ///
+    /// let Err(EncodeError::Unrepresentable(e)) = CP500.encode::<4>("€1–2") else {
+    ///     panic!()
+    /// };
+    /// assert_eq!((e.char(), e.valid_up_to(), e.count()), ('€', 0, 2));
+    /// assert_eq!(e.chars(), [(0, '€'), (4, '–')]);
+    /// assert_eq!(
+    ///     e.to_string(),
+    ///     "Cannot encode '€' at 0, '–' at 4 in the code page"
+    /// );
+    ///
+    /// // Past `MAX_LISTED`, the error only counts the characters.
+    /// let Err(EncodeError::Unrepresentable(e)) = CP500.encode::<6>("€€€€€€") else {
+    ///     panic!()
+    /// };
+    /// assert_eq!((e.chars().len(), e.count()), (4, 6));
+    /// assert_eq!(CP500.unrepresentable("€€€€€€").count(), 6);
+    /// ```
+    pub fn encode<const K: usize>(&self, s: &str) -> Result<[u8; K], EncodeError> {
+        let actual = s.chars().count();
+        if actual != K {
+            return Err(EncodeError::InvalidCharCount {
+                expected: K,
+                actual,
+            });
+        }
+        let mut out = [0u8; K];
+        let mut error = Unrepresentable {
+            listed: [(0, '\0'); Unrepresentable::MAX_LISTED],
+            count: 0,
+        };
+        for (i, (index, c)) in s.char_indices().enumerate() {
+            match self.encode_char(c) {
+                Some(b) => out[i] = b,
+                None => {
+                    if let Some(slot) = error.listed.get_mut(error.count) {
+                        *slot = (index, c);
+                    }
+                    error.count += 1;
+                }
+            }
+        }
+        if error.count == 0 {
+            return Ok(out);
+        }
+        Err(error.into())
+    }
+
+    /// Returns the byte index and value of every character of `s` that the code page cannot
+    /// encode.
+    #[inline]
+    pub fn unrepresentable<'a>(&'a self, s: &'a str) -> impl Iterator<Item = (usize, char)> + 'a {
+        s.char_indices()
+            .filter(move |&(_, c)| self.encode_char(c).is_none())
+    }
+}
+
+/// The code points of the 256 bytes of Windows-1252.
+#[rustfmt::skip]
+const WINDOWS_1252_TABLE: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x0004, 0x0005, 0x0006, 0x0007,
+    0x0008, 0x0009, 0x000A, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x0014, 0x0015, 0x0016, 0x0017,
+    0x0018, 0x0019, 0x001A, 0x001B, 0x001C, 0x001D, 0x001E, 0x001F,
+    0x0020, 0x0021, 0x0022, 0x0023, 0x0024, 0x0025, 0x0026, 0x0027,
+    0x0028, 0x0029, 0x002A, 0x002B, 0x002C, 0x002D, 0x002E, 0x002F,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x003A, 0x003B, 0x003C, 0x003D, 0x003E, 0x003F,
+    0x0040, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F,
+    0x0050, 0x0051, 0x0052, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057,
+    0x0058, 0x0059, 0x005A, 0x005B, 0x005C, 0x005D, 0x005E, 0x005F,
+    0x0060, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F,
+    0x0070, 0x0071, 0x0072, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077,
+    0x0078, 0x0079, 0x007A, 0x007B, 0x007C, 0x007D, 0x007E, 0x007F,
+    0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021,
+    0x02C6, 0x2030, 0x0160, 0x2039, 0x0152, 0x008D, 0x017D, 0x008F,
+    0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022, 0x2013, 0x2014,
+    0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+    0x00A0, 0x00A1, 0x00A2, 0x00A3, 0x00A4, 0x00A5, 0x00A6, 0x00A7,
+    0x00A8, 0x00A9, 0x00AA, 0x00AB, 0x00AC, 0x00AD, 0x00AE, 0x00AF,
+    0x00B0, 0x00B1, 0x00B2, 0x00B3, 0x00B4, 0x00B5, 0x00B6, 0x00B7,
+    0x00B8, 0x00B9, 0x00BA, 0x00BB, 0x00BC, 0x00BD, 0x00BE, 0x00BF,
+    0x00C0, 0x00C1, 0x00C2, 0x00C3, 0x00C4, 0x00C5, 0x00C6, 0x00C7,
+    0x00C8, 0x00C9, 0x00CA, 0x00CB, 0x00CC, 0x00CD, 0x00CE, 0x00CF,
+    0x00D0, 0x00D1, 0x00D2, 0x00D3, 0x00D4, 0x00D5, 0x00D6, 0x00D7,
+    0x00D8, 0x00D9, 0x00DA, 0x00DB, 0x00DC, 0x00DD, 0x00DE, 0x00DF,
+    0x00E0, 0x00E1, 0x00E2, 0x00E3, 0x00E4, 0x00E5, 0x00E6, 0x00E7,
+    0x00E8, 0x00E9, 0x00EA, 0x00EB, 0x00EC, 0x00ED, 0x00EE, 0x00EF,
+    0x00F0, 0x00F1, 0x00F2, 0x00F3, 0x00F4, 0x00F5, 0x00F6, 0x00F7,
+    0x00F8, 0x00F9, 0x00FA, 0x00FB, 0x00FC, 0x00FD, 0x00FE, 0x00FF,
+];
+
+/// The code points of the 256 bytes of EBCDIC code page 037.
+#[rustfmt::skip]
+const CP037_TABLE: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x009C, 0x0009, 0x0086, 0x007F,
+    0x0097, 0x008D, 0x008E, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x009D, 0x0085, 0x0008, 0x0087,
+    0x0018, 0x0019, 0x0092, 0x008F, 0x001C, 0x001D, 0x001E, 0x001F,
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x000A, 0x0017, 0x001B,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x0005, 0x0006, 0x0007,
+    0x0090, 0x0091, 0x0016, 0x0093, 0x0094, 0x0095, 0x0096, 0x0004,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x0014, 0x0015, 0x009E, 0x001A,
+    0x0020, 0x00A0, 0x00E2, 0x00E4, 0x00E0, 0x00E1, 0x00E3, 0x00E5,
+    0x00E7, 0x00F1, 0x00A2, 0x002E, 0x003C, 0x0028, 0x002B, 0x007C,
+    0x0026, 0x00E9, 0x00EA, 0x00EB, 0x00E8, 0x00ED, 0x00EE, 0x00EF,
+    0x00EC, 0x00DF, 0x0021, 0x0024, 0x002A, 0x0029, 0x003B, 0x00AC,
+    0x002D, 0x002F, 0x00C2, 0x00C4, 0x00C0, 0x00C1, 0x00C3, 0x00C5,
+    0x00C7, 0x00D1, 0x00A6, 0x002C, 0x0025, 0x005F, 0x003E, 0x003F,
+    0x00F8, 0x00C9, 0x00CA, 0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF,
+    0x00CC, 0x0060, 0x003A, 0x0023, 0x0040, 0x0027, 0x003D, 0x0022,
+    0x00D8, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x00AB, 0x00BB, 0x00F0, 0x00FD, 0x00FE, 0x00B1,
+    0x00B0, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070,
+    0x0071, 0x0072, 0x00AA, 0x00BA, 0x00E6, 0x00B8, 0x00C6, 0x00A4,
+    0x00B5, 0x007E, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007A, 0x00A1, 0x00BF, 0x00D0, 0x00DD, 0x00DE, 0x00AE,
+    0x005E, 0x00A3, 0x00A5, 0x00B7, 0x00A9, 0x00A7, 0x00B6, 0x00BC,
+    0x00BD, 0x00BE, 0x005B, 0x005D, 0x00AF, 0x00A8, 0x00B4, 0x00D7,
+    0x007B, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x00AD, 0x00F4, 0x00F6, 0x00F2, 0x00F3, 0x00F5,
+    0x007D, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x00B9, 0x00FB, 0x00FC, 0x00F9, 0x00FA, 0x00FF,
+    0x005C, 0x00F7, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x00B2, 0x00D4, 0x00D6, 0x00D2, 0x00D3, 0x00D5,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x00B3, 0x00DB, 0x00DC, 0x00D9, 0x00DA, 0x009F,
+];
+
+/// The code points of the 256 bytes of EBCDIC code page 500.
+#[rustfmt::skip]
+const CP500_TABLE: [u16; 256] = [
+    0x0000, 0x0001, 0x0002, 0x0003, 0x009C, 0x0009, 0x0086, 0x007F,
+    0x0097, 0x008D, 0x008E, 0x000B, 0x000C, 0x000D, 0x000E, 0x000F,
+    0x0010, 0x0011, 0x0012, 0x0013, 0x009D, 0x0085, 0x0008, 0x0087,
+    0x0018, 0x0019, 0x0092, 0x008F, 0x001C, 0x001D, 0x001E, 0x001F,
+    0x0080, 0x0081, 0x0082, 0x0083, 0x0084, 0x000A, 0x0017, 0x001B,
+    0x0088, 0x0089, 0x008A, 0x008B, 0x008C, 0x0005, 0x0006, 0x0007,
+    0x0090, 0x0091, 0x0016, 0x0093, 0x0094, 0x0095, 0x0096, 0x0004,
+    0x0098, 0x0099, 0x009A, 0x009B, 0x0014, 0x0015, 0x009E, 0x001A,
+    0x0020, 0x00A0, 0x00E2, 0x00E4, 0x00E0, 0x00E1, 0x00E3, 0x00E5,
+    0x00E7, 0x00F1, 0x005B, 0x002E, 0x003C, 0x0028, 0x002B, 0x0021,
+    0x0026, 0x00E9, 0x00EA, 0x00EB, 0x00E8, 0x00ED, 0x00EE, 0x00EF,
+    0x00EC, 0x00DF, 0x005D, 0x0024, 0x002A, 0x0029, 0x003B, 0x005E,
+    0x002D, 0x002F, 0x00C2, 0x00C4, 0x00C0, 0x00C1, 0x00C3, 0x00C5,
+    0x00C7, 0x00D1, 0x00A6, 0x002C, 0x0025, 0x005F, 0x003E, 0x003F,
+    0x00F8, 0x00C9, 0x00CA, 0x00CB, 0x00C8, 0x00CD, 0x00CE, 0x00CF,
+    0x00CC, 0x0060, 0x003A, 0x0023, 0x0040, 0x0027, 0x003D, 0x0022,
+    0x00D8, 0x0061, 0x0062, 0x0063, 0x0064, 0x0065, 0x0066, 0x0067,
+    0x0068, 0x0069, 0x00AB, 0x00BB, 0x00F0, 0x00FD, 0x00FE, 0x00B1,
+    0x00B0, 0x006A, 0x006B, 0x006C, 0x006D, 0x006E, 0x006F, 0x0070,
+    0x0071, 0x0072, 0x00AA, 0x00BA, 0x00E6, 0x00B8, 0x00C6, 0x00A4,
+    0x00B5, 0x007E, 0x0073, 0x0074, 0x0075, 0x0076, 0x0077, 0x0078,
+    0x0079, 0x007A, 0x00A1, 0x00BF, 0x00D0, 0x00DD, 0x00DE, 0x00AE,
+    0x00A2, 0x00A3, 0x00A5, 0x00B7, 0x00A9, 0x00A7, 0x00B6, 0x00BC,
+    0x00BD, 0x00BE, 0x00AC, 0x007C, 0x00AF, 0x00A8, 0x00B4, 0x00D7,
+    0x007B, 0x0041, 0x0042, 0x0043, 0x0044, 0x0045, 0x0046, 0x0047,
+    0x0048, 0x0049, 0x00AD, 0x00F4, 0x00F6, 0x00F2, 0x00F3, 0x00F5,
+    0x007D, 0x004A, 0x004B, 0x004C, 0x004D, 0x004E, 0x004F, 0x0050,
+    0x0051, 0x0052, 0x00B9, 0x00FB, 0x00FC, 0x00F9, 0x00FA, 0x00FF,
+    0x005C, 0x00F7, 0x0053, 0x0054, 0x0055, 0x0056, 0x0057, 0x0058,
+    0x0059, 0x005A, 0x00B2, 0x00D4, 0x00D6, 0x00D2, 0x00D3, 0x00D5,
+    0x0030, 0x0031, 0x0032, 0x0033, 0x0034, 0x0035, 0x0036, 0x0037,
+    0x0038, 0x0039, 0x00B3, 0x00DB, 0x00DC, 0x00D9, 0x00DA, 0x009F,
+];
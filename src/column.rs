@@ -0,0 +1,245 @@
+use crate::Str;
+use alloc::vec::Vec;
+use core::{fmt, slice, str};
+
+/// A growable column of [`Str<N>`] values, stored contiguously for columnar operations on
+/// millions of fixed-width strings.
+///
+/// Sorting uses a radix sort over the `N` bytes instead of comparisons, and the operations on
+/// a sorted column, like [`prefix_range`](StrColumn::prefix_range) and
+/// [`binary_search`](StrColumn::binary_search), return views into the column without
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{Str, StrColumn};
+/// let mut column: StrColumn<4> = ["MSFT", "AAPL", "AMZN", "MSFT", "AAPL", "AAPL"]
+///     .into_iter()
+///     .map(|s| Str::try_new(s).unwrap())
+///     .collect();
+///
+/// column.sort();
+/// assert_eq!(column.as_str(), "AAPLAAPLAAPLAMZNMSFTMSFT");
+///
+/// let counts: Vec<_> = column.group_counts().map(|(s, n)| (s.as_str(), n)).collect();
+/// assert_eq!(counts, [("AAPL", 3), ("AMZN", 1), ("MSFT", 2)]);
+///
+/// assert_eq!(column.prefix_range("A").len(), 4);
+///
+/// column.dedup();
+/// assert_eq!(column.as_slice(), ["AAPL", "AMZN", "MSFT"]);
+/// assert_eq!(column.binary_search("AMZN"), Ok(1));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct StrColumn<const N: usize> {
+    values: Vec<Str<N>>,
+}
+
+impl<const N: usize> StrColumn<N> {
+    /// Creates a new empty column.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Creates a new empty column with room for at least `capacity` values.
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of values in the column.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the column has no values.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Appends a value to the end of the column.
+    #[inline]
+    pub fn push(&mut self, s: Str<N>) {
+        self.values.push(s);
+    }
+
+    /// Removes all values from the column, keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// Returns the values of the column as a slice.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[Str<N>] {
+        &self.values
+    }
+
+    /// Returns the values of the column as a mutable slice.
+    #[inline]
+    #[must_use]
+    pub fn as_mut_slice(&mut self) -> &mut [Str<N>] {
+        &mut self.values
+    }
+
+    /// Returns the values of the column concatenated into a single string slice, without
+    /// copying.
+    ///
+    /// Value `i` is at bytes `i * N..(i + 1) * N`.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        let len = self.values.len() * N;
+        // Safety: `Str<N>` is a transparent `[u8; N]`, so the values are `len` contiguous bytes
+        // made of whole UTF-8 strings.
+        unsafe { str::from_utf8_unchecked(slice::from_raw_parts(self.values.as_ptr().cast(), len)) }
+    }
+
+    /// Returns an iterator over the values of the column.
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, Str<N>> {
+        self.values.iter()
+    }
+
+    /// Converts the column into a vector of its values, without copying.
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub fn into_vec(self) -> Vec<Str<N>> {
+        self.values
+    }
+
+    /// Sorts the column.
+    ///
+    /// This is a radix sort that allocates a scratch buffer of `len` values, see
+    /// [`sort_radix_with_scratch`](crate::slice::sort_radix_with_scratch) for details.
+    #[inline]
+    pub fn sort(&mut self) {
+        crate::slice::sort_radix(&mut self.values);
+    }
+
+    /// Removes consecutive repeated values, which removes all duplicates if the column is
+    /// sorted.
+    #[inline]
+    pub fn dedup(&mut self) {
+        self.values.dedup();
+    }
+
+    /// Returns an iterator over the values that start with `prefix`.
+    ///
+    /// This scans the whole column; on a sorted column, [`prefix_range`](StrColumn::prefix_range)
+    /// finds the same values with a binary search.
+    #[inline]
+    pub fn filter_prefix<'a>(&'a self, prefix: &'a str) -> impl Iterator<Item = &'a Str<N>> + 'a {
+        self.values.iter().filter(move |s| s.starts_with(prefix))
+    }
+
+    /// Returns the values that start with `prefix`, in a sorted column.
+    ///
+    /// If the column is not sorted, the returned values are unspecified.
+    #[inline]
+    #[must_use]
+    pub fn prefix_range(&self, prefix: &str) -> &[Str<N>] {
+        &self.values[crate::slice::equal_range_by_prefix(&self.values, prefix)]
+    }
+
+    /// Returns an iterator over the runs of equal values with the length of each run, which
+    /// are the distinct values and their counts if the column is sorted.
+    #[inline]
+    pub fn group_counts(&self) -> impl Iterator<Item = (&Str<N>, usize)> + '_ {
+        self.values
+            .chunk_by(|a, b| a == b)
+            .map(|run| (&run[0], run.len()))
+    }
+
+    /// Binary searches a sorted column for `s`.
+    ///
+    /// Like [`slice::binary_search`], returns `Ok` with the index of a matching value, or `Err`
+    /// with the index where `s` could be inserted to keep the column sorted. If the column is
+    /// not sorted, the result is unspecified.
+    #[inline]
+    pub fn binary_search(&self, s: &str) -> Result<usize, usize> {
+        self.values.binary_search_by(|v| v.as_str().cmp(s))
+    }
+}
+
+impl<const N: usize> fmt::Debug for StrColumn<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.values).finish()
+    }
+}
+
+impl<const N: usize> AsRef<[Str<N>]> for StrColumn<N> {
+    #[inline]
+    fn as_ref(&self) -> &[Str<N>] {
+        &self.values
+    }
+}
+
+impl<const N: usize> AsMut<[Str<N>]> for StrColumn<N> {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [Str<N>] {
+        &mut self.values
+    }
+}
+
+impl<const N: usize> From<Vec<Str<N>>> for StrColumn<N> {
+    #[inline]
+    fn from(values: Vec<Str<N>>) -> Self {
+        Self { values }
+    }
+}
+
+impl<const N: usize> From<StrColumn<N>> for Vec<Str<N>> {
+    #[inline]
+    fn from(column: StrColumn<N>) -> Self {
+        column.values
+    }
+}
+
+impl<const N: usize> FromIterator<Str<N>> for StrColumn<N> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Str<N>>>(iter: I) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<const N: usize> Extend<Str<N>> for StrColumn<N> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Str<N>>>(&mut self, iter: I) {
+        self.values.extend(iter);
+    }
+}
+
+impl<'a, const N: usize> IntoIterator for &'a StrColumn<N> {
+    type Item = &'a Str<N>;
+    type IntoIter = slice::Iter<'a, Str<N>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.iter()
+    }
+}
+
+impl<const N: usize> IntoIterator for StrColumn<N> {
+    type Item = Str<N>;
+    type IntoIter = alloc::vec::IntoIter<Str<N>>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.values.into_iter()
+    }
+}
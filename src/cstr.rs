@@ -0,0 +1,441 @@
+use crate::{InvalidLength, Str};
+use core::{
+    ffi::{c_char, CStr},
+    fmt, hash, str,
+};
+
+/// The error returned when bytes or a string do not fit in a [`CStrArray`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum CStrArrayError {
+    /// The bytes have no NUL terminator.
+    NotNulTerminated,
+    /// The string has a NUL byte, which would end the C string early.
+    InteriorNul {
+        /// The index of the first NUL byte.
+        index: usize,
+    },
+    /// The string does not fit in `N - 1` bytes, leaving room for the NUL terminator.
+    TooLong {
+        /// The maximum length of the string in bytes.
+        capacity: usize,
+        /// The length of the string in bytes.
+        actual: usize,
+    },
+    /// The C string is not valid UTF-8.
+    Utf8(str::Utf8Error),
+    /// The C string does not have the length of the [`Str<M>`] it is converted into.
+    InvalidLength(InvalidLength),
+}
+
+impl fmt::Display for CStrArrayError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CStrArrayError::NotNulTerminated => f.write_str("Expected a NUL terminator"),
+            CStrArrayError::InteriorNul { index } => {
+                write!(f, "Unexpected NUL byte found at {}", index)
+            }
+            CStrArrayError::TooLong { capacity, actual } => write!(
+                f,
+                "Expected string with at most {} bytes, but got {}",
+                capacity, actual
+            ),
+            CStrArrayError::Utf8(e) => fmt::Display::fmt(e, f),
+            CStrArrayError::InvalidLength(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CStrArrayError {}
+
+impl From<str::Utf8Error> for CStrArrayError {
+    #[inline]
+    fn from(e: str::Utf8Error) -> Self {
+        CStrArrayError::Utf8(e)
+    }
+}
+
+impl From<InvalidLength> for CStrArrayError {
+    #[inline]
+    fn from(e: InvalidLength) -> Self {
+        CStrArrayError::InvalidLength(e)
+    }
+}
+
+/// A NUL-terminated C string stored in an array of `N` bytes, like a `char name[N]` field.
+///
+/// There is always a NUL byte within the `N` bytes, so strings are at most `N - 1` bytes long,
+/// and `N` must be at least 1, which is checked at compile time. The type is
+/// `repr(transparent)`, so `#[repr(C)]` structs shared with C can embed it directly. Bytes after
+/// the first NUL are ignored by comparisons and hashing.
+///
+/// # Safety
+///
+/// Code that writes the bytes through a pointer, like C code filling a struct, must leave a NUL
+/// byte within the `N` bytes. Safe code cannot break this, and the methods stay sound if it is
+/// broken anyway: [`len`](Self::len) is then `N`, and [`as_c_str`](Self::as_c_str) panics. C
+/// code reading [`as_ptr`](Self::as_ptr) would read past the array, though.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{CStrArray, Str};
+/// #[repr(C)]
+/// struct Device {
+///     id: u32,
+///     name: CStrArray<16>,
+/// }
+///
+/// let device = Device { id: 1, name: CStrArray::try_new("eth0").unwrap() };
+/// assert_eq!(size_of::<Device>(), 20);
+/// assert_eq!(device.name.as_c_str(), c"eth0");
+/// assert_eq!(device.name.as_str(), Ok("eth0"));
+///
+/// let name: Str<4> = device.name.to_str_array().unwrap();
+/// assert_eq!(name, "eth0");
+/// ```
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct CStrArray<const N: usize> {
+    v: [u8; N],
+}
+
+impl<const N: usize> CStrArray<N> {
+    /// Wraps an array of bytes, which must contain a NUL byte.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrArrayError::NotNulTerminated`] if there is no NUL byte.
+    #[inline]
+    pub const fn from_bytes(v: [u8; N]) -> Result<Self, CStrArrayError> {
+        const { assert!(N > 0, "CStrArray<N> requires N > 0") };
+        let mut i = 0;
+        while i < N {
+            if v[i] == 0 {
+                return Ok(Self { v });
+            }
+            i += 1;
+        }
+        Err(CStrArrayError::NotNulTerminated)
+    }
+
+    /// Wraps an array of C characters, which must contain a NUL character.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrArrayError::NotNulTerminated`] if there is no NUL character.
+    #[inline]
+    pub const fn from_c_chars(v: [c_char; N]) -> Result<Self, CStrArrayError> {
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            bytes[i] = v[i] as u8;
+            i += 1;
+        }
+        Self::from_bytes(bytes)
+    }
+
+    /// Copies a `&str` of at most `N - 1` bytes into a new `CStrArray`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrArrayError::InteriorNul`] if the string has a NUL byte, and
+    /// [`CStrArrayError::TooLong`] if it is longer than `N - 1` bytes.
+    #[inline]
+    pub const fn try_new(s: &str) -> Result<Self, CStrArrayError> {
+        let bytes = s.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            if bytes[i] == 0 {
+                return Err(CStrArrayError::InteriorNul { index: i });
+            }
+            i += 1;
+        }
+        Self::from_bytes_without_nul(bytes)
+    }
+
+    /// Copies a `&str` into a new `CStrArray`, truncated to its first `N - 1` bytes.
+    ///
+    /// The string is cut at a character boundary, so the C string stays valid UTF-8, and at its
+    /// first NUL byte if any, where C would stop reading it anyway.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::CStrArray;
+    /// let s = CStrArray::<5>::new_truncated("naïve");
+    /// assert_eq!(s.as_str(), Ok("naï"));
+    ///
+    /// let s = CStrArray::<5>::new_truncated("a\0b");
+    /// assert_eq!(s.as_str(), Ok("a"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new_truncated(s: &str) -> Self {
+        const { assert!(N > 0, "CStrArray<N> requires N > 0") };
+        let s = s.split('\0').next().unwrap_or_default();
+        let mut len = s.len().min(N - 1);
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+        let mut v = [0u8; N];
+        v[..len].copy_from_slice(&s.as_bytes()[..len]);
+        Self { v }
+    }
+
+    /// Copies a `&CStr` of at most `N - 1` bytes into a new `CStrArray`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrArrayError::TooLong`] if the C string is longer than `N - 1` bytes.
+    #[inline]
+    pub const fn from_c_str(s: &CStr) -> Result<Self, CStrArrayError> {
+        Self::from_bytes_without_nul(s.to_bytes())
+    }
+
+    /// Copies a `&CStr` into a new `CStrArray`, truncated to its first `N - 1` bytes.
+    #[inline]
+    #[must_use]
+    pub fn from_c_str_truncated(s: &CStr) -> Self {
+        const { assert!(N > 0, "CStrArray<N> requires N > 0") };
+        let bytes = s.to_bytes();
+        let len = bytes.len().min(N - 1);
+        let mut v = [0u8; N];
+        v[..len].copy_from_slice(&bytes[..len]);
+        Self { v }
+    }
+
+    const fn from_bytes_without_nul(bytes: &[u8]) -> Result<Self, CStrArrayError> {
+        const { assert!(N > 0, "CStrArray<N> requires N > 0") };
+        if bytes.len() >= N {
+            return Err(CStrArrayError::TooLong {
+                capacity: N - 1,
+                actual: bytes.len(),
+            });
+        }
+        let mut v = [0u8; N];
+        // We use `while` because `copy_from_slice` is not const fn yet.
+        let mut i = 0;
+        while i < bytes.len() {
+            v[i] = bytes[i];
+            i += 1;
+        }
+        Ok(Self { v })
+    }
+
+    /// Returns the length of the C string in bytes, without the NUL terminator.
+    ///
+    /// This is `N` if foreign code overwrote the NUL terminator.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        let mut i = 0;
+        while i < N && self.v[i] != 0 {
+            i += 1;
+        }
+        i
+    }
+
+    /// Returns `true` if the C string has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.v[0] == 0
+    }
+
+    /// Returns the maximum length of the C string, `N - 1`.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N - 1
+    }
+
+    /// Returns the bytes of the C string, without the NUL terminator.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(&self) -> &[u8] {
+        self.v.split_at(self.len()).0
+    }
+
+    /// Returns the whole array, including the NUL terminator and the bytes after it.
+    #[inline]
+    #[must_use]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.v
+    }
+
+    /// Returns the C string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if foreign code overwrote the NUL terminator, see the
+    /// [type docs](CStrArray#safety).
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub const fn as_c_str(&self) -> &CStr {
+        match CStr::from_bytes_until_nul(&self.v) {
+            Ok(s) => s,
+            Err(_) => panic!("CStrArray has no NUL terminator"),
+        }
+    }
+
+    /// Returns the C string as a string slice, if it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf8Error`](str::Utf8Error) if the C string is not valid UTF-8.
+    #[inline]
+    pub const fn as_str(&self) -> Result<&str, str::Utf8Error> {
+        str::from_utf8(self.to_bytes())
+    }
+
+    /// Returns a pointer to the C string, to pass to C functions.
+    #[inline]
+    #[must_use]
+    pub const fn as_ptr(&self) -> *const c_char {
+        self.v.as_ptr().cast()
+    }
+
+    /// Converts the C string into a [`Str<M>`] if it is valid UTF-8 of exactly `M` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CStrArrayError::Utf8`] if the C string is not valid UTF-8, and
+    /// [`CStrArrayError::InvalidLength`] if it is not exactly `M` bytes long.
+    #[inline]
+    pub const fn to_str_array<const M: usize>(&self) -> Result<Str<M>, CStrArrayError> {
+        match self.as_str() {
+            Ok(s) => match Str::try_new(s) {
+                Ok(s) => Ok(s),
+                Err(e) => Err(CStrArrayError::InvalidLength(e)),
+            },
+            Err(e) => Err(CStrArrayError::Utf8(e)),
+        }
+    }
+}
+
+impl<const N: usize> Default for CStrArray<N> {
+    #[inline]
+    fn default() -> Self {
+        const { assert!(N > 0, "CStrArray<N> requires N > 0") };
+        Self { v: [0; N] }
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for CStrArray<N> {
+    type Error = CStrArrayError;
+    /// Try to convert a `&str` into a [`CStrArray<N>`].
+    ///
+    /// The result will fail if the string has a NUL byte or is longer than `N - 1` bytes.
+    #[inline]
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const N: usize> TryFrom<&CStr> for CStrArray<N> {
+    type Error = CStrArrayError;
+    /// Try to convert a `&CStr` into a [`CStrArray<N>`].
+    ///
+    /// The result will fail if the C string is longer than `N - 1` bytes.
+    #[inline]
+    fn try_from(v: &CStr) -> Result<Self, Self::Error> {
+        Self::from_c_str(v)
+    }
+}
+
+impl<const N: usize> TryFrom<[u8; N]> for CStrArray<N> {
+    type Error = CStrArrayError;
+    /// Try to convert an array of bytes into a [`CStrArray<N>`].
+    ///
+    /// The result will fail if the array has no NUL byte.
+    #[inline]
+    fn try_from(v: [u8; N]) -> Result<Self, Self::Error> {
+        Self::from_bytes(v)
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<Str<M>> for CStrArray<N> {
+    type Error = CStrArrayError;
+    /// Try to convert a [`Str<M>`] into a [`CStrArray<N>`].
+    ///
+    /// The result will fail if the string has a NUL byte or is longer than `N - 1` bytes.
+    #[inline]
+    fn try_from(v: Str<M>) -> Result<Self, Self::Error> {
+        Self::try_new(&v)
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<CStrArray<N>> for Str<M> {
+    type Error = CStrArrayError;
+    /// Try to convert a [`CStrArray<N>`] into a [`Str<M>`].
+    ///
+    /// The result will fail if the C string is not valid UTF-8 of exactly `M` bytes.
+    #[inline]
+    fn try_from(v: CStrArray<N>) -> Result<Self, Self::Error> {
+        v.to_str_array()
+    }
+}
+
+impl<const N: usize> AsRef<CStr> for CStrArray<N> {
+    #[inline]
+    fn as_ref(&self) -> &CStr {
+        self.as_c_str()
+    }
+}
+
+impl<const N: usize> PartialEq for CStrArray<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+impl<const N: usize> Eq for CStrArray<N> {}
+
+impl<const N: usize> PartialEq<CStr> for CStrArray<N> {
+    #[inline]
+    fn eq(&self, other: &CStr) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&CStr> for CStrArray<N> {
+    #[inline]
+    fn eq(&self, other: &&CStr) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for CStrArray<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for CStrArray<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.to_bytes() == other.as_bytes()
+    }
+}
+
+impl<const N: usize> hash::Hash for CStrArray<N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        // The bytes with their NUL, which is how `CStr` hashes.
+        self.v[..(self.len() + 1).min(N)].hash(hasher)
+    }
+}
+
+impl<const N: usize> fmt::Debug for CStrArray<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Like `CStr`'s `Debug`, without requiring the NUL terminator.
+        write!(f, "\"{}\"", self.to_bytes().escape_ascii())
+    }
+}
@@ -0,0 +1,219 @@
+//! Conversions between [`Str<N>`] and other inline string crates.
+//!
+//! Conversions into a fixed-capacity string are infallible and checked at compile time: using
+//! `From<Str<N>>` for a type with a capacity smaller than `N` fails to build.
+
+use crate::{InvalidLength, Str};
+
+macro_rules! impl_cmp {
+    ([$($generics: tt)*] $other: ty) => {
+        impl<const N: usize, $($generics)*> PartialEq<$other> for Str<N> {
+            #[inline]
+            fn eq(&self, other: &$other) -> bool {
+                <str as PartialEq>::eq(self.as_str(), other.as_ref())
+            }
+        }
+
+        impl<const N: usize, $($generics)*> PartialOrd<$other> for Str<N> {
+            #[inline]
+            fn partial_cmp(&self, other: &$other) -> Option<core::cmp::Ordering> {
+                <str as PartialOrd>::partial_cmp(self.as_str(), other.as_ref())
+            }
+        }
+
+        impl<const N: usize, $($generics)*> PartialOrd<Str<N>> for $other {
+            #[inline]
+            fn partial_cmp(&self, other: &Str<N>) -> Option<core::cmp::Ordering> {
+                <str as PartialOrd>::partial_cmp(self.as_ref(), other.as_str())
+            }
+        }
+    };
+}
+
+macro_rules! impl_try_from {
+    ([$($generics: tt)*] $other: ty) => {
+        impl<const N: usize, $($generics)*> TryFrom<$other> for Str<N> {
+            type Error = InvalidLength;
+            /// Try to convert the string into a [`Str<N>`].
+            ///
+            /// The result will fail if the string's length is not exactly `N`.
+            #[inline]
+            fn try_from(v: $other) -> Result<Self, Self::Error> {
+                Self::try_new(&v)
+            }
+        }
+
+        impl<'a, const N: usize, $($generics)*> TryFrom<&'a $other> for Str<N> {
+            type Error = InvalidLength;
+            /// Try to convert the string into a [`Str<N>`].
+            ///
+            /// The result will fail if the string's length is not exactly `N`.
+            #[inline]
+            fn try_from(v: &'a $other) -> Result<Self, Self::Error> {
+                Self::try_new(v)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "heapless")]
+mod heapless_impls {
+    use super::*;
+
+    impl_try_from! { [const M: usize] heapless::String<M> }
+    impl_cmp! { [const M: usize] heapless::String<M> }
+
+    impl<const N: usize, const M: usize> PartialEq<Str<N>> for heapless::String<M> {
+        #[inline]
+        fn eq(&self, other: &Str<N>) -> bool {
+            <str as PartialEq>::eq(self, other.as_str())
+        }
+    }
+
+    impl<const N: usize, const M: usize> From<Str<N>> for heapless::String<M> {
+        /// Converts a [`Str<N>`] into a `heapless::String<M>` with `M >= N`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let h: heapless::String<8> = s.into();
+        /// assert_eq!(h, s);
+        /// assert_eq!(Str::<3>::try_from(h), Ok(s));
+        /// ```
+        ///
+        /// A smaller capacity is rejected at compile time:
+        ///
+        /// ```compile_fail
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let h: heapless::String<2> = s.into();
+        /// ```
+        #[inline]
+        fn from(v: Str<N>) -> Self {
+            const { assert!(M >= N, "heapless::String is too small for this Str") };
+            let mut s = heapless::String::new();
+            match s.push_str(v.as_str()) {
+                Ok(()) => s,
+                Err(()) => unreachable!(),
+            }
+        }
+    }
+}
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec_impls {
+    use super::*;
+
+    impl_try_from! { [const M: usize] arrayvec::ArrayString<M> }
+    impl_cmp! { [const M: usize] arrayvec::ArrayString<M> }
+
+    impl<const N: usize, const M: usize> PartialEq<Str<N>> for arrayvec::ArrayString<M> {
+        #[inline]
+        fn eq(&self, other: &Str<N>) -> bool {
+            <str as PartialEq>::eq(self, other.as_str())
+        }
+    }
+
+    impl<const N: usize, const M: usize> From<Str<N>> for arrayvec::ArrayString<M> {
+        /// Converts a [`Str<N>`] into an `arrayvec::ArrayString<M>` with `M >= N`.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let a: arrayvec::ArrayString<3> = s.into();
+        /// assert_eq!(a, s);
+        /// assert_eq!(Str::<3>::try_from(&a), Ok(s));
+        /// ```
+        ///
+        /// A smaller capacity is rejected at compile time:
+        ///
+        /// ```compile_fail
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let a: arrayvec::ArrayString<2> = s.into();
+        /// ```
+        #[inline]
+        fn from(v: Str<N>) -> Self {
+            const { assert!(M >= N, "arrayvec::ArrayString is too small for this Str") };
+            let mut s = arrayvec::ArrayString::new();
+            s.push_str(v.as_str());
+            s
+        }
+    }
+}
+
+#[cfg(feature = "smol_str")]
+mod smol_str_impls {
+    use super::*;
+    use smol_str::SmolStr;
+
+    impl_try_from! { [] SmolStr }
+    impl_cmp! { [] SmolStr }
+
+    impl<const N: usize> PartialEq<Str<N>> for SmolStr {
+        #[inline]
+        fn eq(&self, other: &Str<N>) -> bool {
+            <str as PartialEq>::eq(self, other.as_str())
+        }
+    }
+
+    impl<const N: usize> From<Str<N>> for SmolStr {
+        /// Converts a [`Str<N>`] into a `SmolStr`, inline if it is short enough.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let smol = smol_str::SmolStr::from(s);
+        /// assert_eq!(smol, s);
+        /// assert_eq!(Str::<3>::try_from(smol), Ok(s));
+        /// ```
+        #[inline]
+        fn from(v: Str<N>) -> Self {
+            SmolStr::new(v.as_str())
+        }
+    }
+}
+
+#[cfg(feature = "compact_str")]
+mod compact_str_impls {
+    use super::*;
+    use compact_str::CompactString;
+
+    impl_try_from! { [] CompactString }
+    impl_cmp! { [] CompactString }
+
+    // `CompactString` already implements `PartialEq<T>` for every `T: AsRef<str>`.
+
+    impl<const N: usize> From<Str<N>> for CompactString {
+        /// Converts a [`Str<N>`] into a `CompactString`, inline if it is short enough.
+        ///
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let s: Str<3> = Str::try_new("foo").unwrap();
+        /// let compact = compact_str::CompactString::from(s);
+        /// assert_eq!(compact, s);
+        /// assert_eq!(s, compact);
+        /// assert_eq!(Str::<3>::try_from(&compact), Ok(s));
+        /// ```
+        #[inline]
+        fn from(v: Str<N>) -> Self {
+            CompactString::new(v.as_str())
+        }
+    }
+}
@@ -0,0 +1,593 @@
+//! Order-preserving encoding of composite keys, for key-value stores like RocksDB and sled that
+//! sort keys by their bytes.
+//!
+//! Encoding a value with [`EncodeKey`] appends bytes to a buffer such that comparing two
+//! encoded keys with `memcmp` orders them like the values themselves, and tuples of values like
+//! tuples: by their first field, then their second, and so on. [`decode`] reads the values back
+//! without allocating, borrowing strings from the key when it can.
+//!
+//! | Type | Encoding |
+//! |------|----------|
+//! | [`Str<N>`] | its `N` bytes |
+//! | [`Padded<N>`] | the string padded with zeros to `N` bytes, then its length |
+//! | `str` | the string with each NUL escaped as `00 FF`, then the terminator `00 01` |
+//! | unsigned integers | big-endian |
+//! | signed integers | big-endian, with the sign bit flipped |
+//! | tuples | each field in order |
+//!
+//! Variable-length strings decode to an [`EscapedStr`], a view of the key that only needs
+//! copying if the string contains NUL characters.
+//!
+//! # Examples
+//!
+//! ```
+//! use str_array::{key::{self, EncodeKey, EscapedStr}, Str};
+//!
+//! let exchange: Str<4> = Str::try_new("XNYS").unwrap();
+//! let mut a = Vec::new();
+//! (exchange, "AAPL", -5i64).encode_key(&mut a);
+//! let mut b = Vec::new();
+//! (exchange, "AAPL", 3i64).encode_key(&mut b);
+//! let mut c = Vec::new();
+//! (exchange, "AAPL.W", -10i64).encode_key(&mut c);
+//!
+//! assert!(a < b && b < c);
+//!
+//! let (e, symbol, n): (Str<4>, EscapedStr, i64) = key::decode(&a).unwrap();
+//! assert_eq!((e, symbol.as_str(), n), (exchange, Some("AAPL"), -5));
+//! ```
+
+use crate::{InvalidLength, Str};
+use alloc::vec::Vec;
+use core::{fmt, str};
+
+/// The error returned when a key cannot be decoded.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum KeyError {
+    /// The key ended in the middle of a value.
+    UnexpectedEnd,
+    /// A NUL byte in a variable-length string is not followed by `FF` or `01`.
+    InvalidEscape,
+    /// The length of a padded string is larger than its capacity, or its padding is not zeros.
+    InvalidPadding,
+    /// A string is not valid UTF-8.
+    Utf8(str::Utf8Error),
+    /// The key has bytes left after the decoded value.
+    TrailingBytes {
+        /// The number of bytes left.
+        len: usize,
+    },
+}
+
+impl fmt::Display for KeyError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::UnexpectedEnd => f.write_str("Unexpected end of key"),
+            KeyError::InvalidEscape => f.write_str("Invalid escape in key string"),
+            KeyError::InvalidPadding => f.write_str("Invalid padding in key string"),
+            KeyError::Utf8(e) => fmt::Display::fmt(e, f),
+            KeyError::TrailingBytes { len } => {
+                write!(f, "Expected end of key, but got {} more bytes", len)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KeyError {}
+
+impl From<str::Utf8Error> for KeyError {
+    #[inline]
+    fn from(e: str::Utf8Error) -> Self {
+        KeyError::Utf8(e)
+    }
+}
+
+/// The error returned when a string does not fit in a [`Padded`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct TooLong {
+    capacity: usize,
+    actual: usize,
+}
+
+impl TooLong {
+    /// Returns the capacity of the `Padded`, in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    #[must_use]
+    pub const fn actual(&self) -> usize {
+        self.actual
+    }
+}
+
+impl fmt::Display for TooLong {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected string with at most {} bytes, but got {}",
+            self.capacity, self.actual
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TooLong {}
+
+/// A value that can be encoded into an order-preserving key.
+///
+/// The encoding of a value is never a prefix of the encoding of another value of the same
+/// type, so encoded values can be concatenated and still compare field by field.
+pub trait EncodeKey {
+    /// Appends the encoding of `self` to `out`.
+    fn encode_key(&self, out: &mut Vec<u8>);
+}
+
+/// A value that can be decoded from a key encoded with [`EncodeKey`].
+pub trait DecodeKey<'a>: Sized {
+    /// Decodes a value from the start of `input`, and advances `input` past it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`KeyError`] if `input` does not start with a valid encoding of the value.
+    fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError>;
+}
+
+/// Decodes a value from a whole key.
+///
+/// # Errors
+///
+/// Returns [`KeyError`] if the key is not a valid encoding of the value, and
+/// [`KeyError::TrailingBytes`] if it has bytes left after the value.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use str_array::key::{self, EncodeKey, KeyError};
+/// let mut buf = Vec::new();
+/// (7u16, -1i8).encode_key(&mut buf);
+/// assert_eq!(buf, [0x00, 0x07, 0x7F]);
+///
+/// assert_eq!(key::decode::<(u16, i8)>(&buf), Ok((7, -1)));
+/// assert_eq!(key::decode::<u16>(&buf), Err(KeyError::TrailingBytes { len: 1 }));
+/// ```
+#[inline]
+pub fn decode<'a, T: DecodeKey<'a>>(mut key: &'a [u8]) -> Result<T, KeyError> {
+    let value = T::decode_key(&mut key)?;
+    if !key.is_empty() {
+        return Err(KeyError::TrailingBytes { len: key.len() });
+    }
+    Ok(value)
+}
+
+/// Splits the first `len` bytes off `input`.
+#[inline]
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], KeyError> {
+    if input.len() < len {
+        return Err(KeyError::UnexpectedEnd);
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+impl<T: EncodeKey + ?Sized> EncodeKey for &T {
+    #[inline]
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        (**self).encode_key(out);
+    }
+}
+
+impl<const N: usize> EncodeKey for Str<N> {
+    #[inline]
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.v);
+    }
+}
+
+impl<'a, const N: usize> DecodeKey<'a> for Str<N> {
+    #[inline]
+    fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+        let mut v = [0u8; N];
+        v.copy_from_slice(take(input, N)?);
+        Ok(Str::from_utf8_fast(v)?)
+    }
+}
+
+macro_rules! impl_int {
+    ($($unsigned:ty, $signed:ty;)*) => {$(
+        impl EncodeKey for $unsigned {
+            #[inline]
+            fn encode_key(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_be_bytes());
+            }
+        }
+
+        impl<'a> DecodeKey<'a> for $unsigned {
+            #[inline]
+            fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+                const LEN: usize = core::mem::size_of::<$unsigned>();
+                let mut bytes = [0u8; LEN];
+                bytes.copy_from_slice(take(input, LEN)?);
+                Ok(<$unsigned>::from_be_bytes(bytes))
+            }
+        }
+
+        impl EncodeKey for $signed {
+            #[inline]
+            fn encode_key(&self, out: &mut Vec<u8>) {
+                // Flipping the sign bit orders negative numbers before positive ones.
+                (*self as $unsigned ^ !(<$unsigned>::MAX >> 1)).encode_key(out);
+            }
+        }
+
+        impl<'a> DecodeKey<'a> for $signed {
+            #[inline]
+            fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+                let bits = <$unsigned>::decode_key(input)?;
+                Ok((bits ^ !(<$unsigned>::MAX >> 1)) as $signed)
+            }
+        }
+    )*};
+}
+
+impl_int! {
+    u8, i8;
+    u16, i16;
+    u32, i32;
+    u64, i64;
+    u128, i128;
+}
+
+/// A string of at most `N` bytes, encoded with a fixed width.
+///
+/// The string is padded with zeros to `N` bytes and followed by its length, in as few
+/// big-endian bytes as fit `N`. Unlike a variable-length `str`, it decodes to a string slice
+/// borrowed from the key, and every value of the type has the same encoded length.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::key::{self, EncodeKey, Padded};
+/// let mut buf = Vec::new();
+/// Padded::<6>::try_new("ab").unwrap().encode_key(&mut buf);
+/// assert_eq!(buf, b"ab\0\0\0\0\x02");
+///
+/// let s: Padded<6> = key::decode(&buf).unwrap();
+/// assert_eq!(s.as_str(), "ab");
+///
+/// let err = Padded::<6>::try_new("abcdefg").unwrap_err();
+/// assert_eq!(err.to_string(), "Expected string with at most 6 bytes, but got 7");
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Padded<'a, const N: usize> {
+    s: &'a str,
+}
+
+impl<'a, const N: usize> Padded<'a, N> {
+    /// The number of bytes of the length after the padding.
+    const LEN_BYTES: usize = (usize::BITS - N.leading_zeros()).div_ceil(8) as usize;
+
+    /// Wraps a string of at most `N` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TooLong`] if the string is longer than `N` bytes.
+    #[inline]
+    pub const fn try_new(s: &'a str) -> Result<Self, TooLong> {
+        if s.len() > N {
+            return Err(TooLong {
+                capacity: N,
+                actual: s.len(),
+            });
+        }
+        Ok(Self { s })
+    }
+
+    /// Returns the string.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.s
+    }
+}
+
+impl<const N: usize> EncodeKey for Padded<'_, N> {
+    #[inline]
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.s.as_bytes());
+        out.resize(out.len() + N - self.s.len(), 0);
+        let len = self.s.len().to_be_bytes();
+        out.extend_from_slice(&len[len.len() - Self::LEN_BYTES..]);
+    }
+}
+
+impl<'a, const N: usize> DecodeKey<'a> for Padded<'a, N> {
+    #[inline]
+    fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+        let padded = take(input, N)?;
+        let len = take(input, Self::LEN_BYTES)?
+            .iter()
+            .fold(0, |len, &b| len << 8 | b as usize);
+        if len > N || padded[len..].iter().any(|&b| b != 0) {
+            return Err(KeyError::InvalidPadding);
+        }
+        Ok(Self {
+            s: str::from_utf8(&padded[..len])?,
+        })
+    }
+}
+
+impl<const N: usize> fmt::Display for Padded<'_, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.s, f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for Padded<'_, N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.s, f)
+    }
+}
+
+const NUL: u8 = 0x00;
+const ESCAPED_NUL: u8 = 0xFF;
+const TERMINATOR: u8 = 0x01;
+
+impl EncodeKey for str {
+    #[inline]
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        for segment in self.as_bytes().split(|&b| b == NUL) {
+            if segment.as_ptr() != self.as_ptr() {
+                out.extend_from_slice(&[NUL, ESCAPED_NUL]);
+            }
+            out.extend_from_slice(segment);
+        }
+        out.extend_from_slice(&[NUL, TERMINATOR]);
+    }
+}
+
+/// A variable-length string decoded from a key, borrowed from the key in its escaped form.
+///
+/// If the string has no NUL characters, its escaped form is the string itself and
+/// [`as_str`](EscapedStr::as_str) returns it; otherwise it can be copied into a buffer or a
+/// [`Str<N>`] without allocating.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::key::{self, EncodeKey, EscapedStr};
+/// let mut buf = Vec::new();
+/// "a\0b".encode_key(&mut buf);
+/// assert_eq!(buf, b"a\0\xFFb\0\x01");
+///
+/// let s: EscapedStr = key::decode(&buf).unwrap();
+/// assert_eq!(s, "a\0b");
+/// assert_eq!(s.as_str(), None);
+/// assert_eq!(s.unescape_into(&mut [0u8; 8]), Some("a\0b"));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EscapedStr<'a> {
+    // The encoded string without its terminator, which is valid UTF-8 once unescaped.
+    raw: &'a [u8],
+}
+
+impl<'a> EscapedStr<'a> {
+    /// Returns the string if it has no NUL characters, and `None` otherwise.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> Option<&'a str> {
+        if self.raw.contains(&NUL) {
+            return None;
+        }
+        // Safety: without escapes, the raw bytes are the string, which we validated.
+        Some(unsafe { str::from_utf8_unchecked(self.raw) })
+    }
+
+    /// Returns the length of the unescaped string in bytes.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.raw.len() - self.raw.iter().filter(|&&b| b == NUL).count()
+    }
+
+    /// Returns `true` if the string is empty.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.raw.is_empty()
+    }
+
+    /// Returns an iterator over the parts of the string between its NUL characters.
+    #[inline]
+    pub fn segments(&self) -> impl Iterator<Item = &'a str> + Clone + 'a {
+        let raw = self.raw;
+        raw.split(|&b| b == NUL).map(move |segment| {
+            // Every segment but the first starts with the second byte of an escape.
+            let segment = if segment.as_ptr() == raw.as_ptr() {
+                segment
+            } else {
+                &segment[1..]
+            };
+            // Safety: NUL is a whole character, so each segment is valid UTF-8 on its own, which
+            // we validated.
+            unsafe { str::from_utf8_unchecked(segment) }
+        })
+    }
+
+    /// Copies the unescaped string into `buf`, and returns it.
+    ///
+    /// Returns `None` if `buf` is shorter than [`len`](EscapedStr::len).
+    #[inline]
+    pub fn unescape_into<'b>(&self, buf: &'b mut [u8]) -> Option<&'b str> {
+        let out = buf.get_mut(..self.len())?;
+        let mut i = 0;
+        for (n, segment) in self.segments().enumerate() {
+            if n > 0 {
+                out[i] = NUL;
+                i += 1;
+            }
+            out[i..i + segment.len()].copy_from_slice(segment.as_bytes());
+            i += segment.len();
+        }
+        // Safety: we copied whole UTF-8 segments and NUL characters.
+        Some(unsafe { str::from_utf8_unchecked(out) })
+    }
+
+    /// Copies the unescaped string into a [`Str<N>`] if its length is exactly `N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string is not exactly `N` bytes long.
+    #[inline]
+    pub fn to_str_array<const N: usize>(&self) -> Result<Str<N>, InvalidLength> {
+        let mut v = [0u8; N];
+        if self.len() != N || self.unescape_into(&mut v).is_none() {
+            return Err(InvalidLength {
+                expected: N,
+                actual: self.len(),
+            });
+        }
+        // Safety: we copied the whole unescaped string, which is valid UTF-8.
+        Ok(unsafe { Str::from_utf8_unchecked(v) })
+    }
+}
+
+impl EncodeKey for EscapedStr<'_> {
+    #[inline]
+    fn encode_key(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(self.raw);
+        out.extend_from_slice(&[NUL, TERMINATOR]);
+    }
+}
+
+impl<'a> DecodeKey<'a> for EscapedStr<'a> {
+    fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+        let bytes = *input;
+        let mut start = 0;
+        loop {
+            let Some(nul) = bytes[start..].iter().position(|&b| b == NUL) else {
+                return Err(KeyError::UnexpectedEnd);
+            };
+            let end = start + nul;
+            str::from_utf8(&bytes[start..end])?;
+            match bytes.get(end + 1) {
+                Some(&ESCAPED_NUL) => start = end + 2,
+                Some(&TERMINATOR) => {
+                    *input = &bytes[end + 2..];
+                    return Ok(Self { raw: &bytes[..end] });
+                }
+                Some(_) => return Err(KeyError::InvalidEscape),
+                None => return Err(KeyError::UnexpectedEnd),
+            }
+        }
+    }
+}
+
+impl PartialEq<str> for EscapedStr<'_> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        let mut rest = other;
+        for (n, segment) in self.segments().enumerate() {
+            if n > 0 {
+                let Some(tail) = rest.strip_prefix('\0') else {
+                    return false;
+                };
+                rest = tail;
+            }
+            let Some(tail) = rest.strip_prefix(segment) else {
+                return false;
+            };
+            rest = tail;
+        }
+        rest.is_empty()
+    }
+}
+
+impl PartialEq<&str> for EscapedStr<'_> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl fmt::Display for EscapedStr<'_> {
+    /// Writes the unescaped string, padded like a `str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::key::{self, EncodeKey, EscapedStr};
+    /// let mut buf = Vec::new();
+    /// ("ab", "a\0b").encode_key(&mut buf);
+    /// let (a, b): (EscapedStr, EscapedStr) = key::decode(&buf).unwrap();
+    ///
+    /// assert_eq!(format!("[{a:>4}]"), "[  ab]");
+    /// assert_eq!(format!("[{b:-<5}]"), "[a\0b--]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(s) = self.as_str() {
+            return f.pad(s);
+        }
+        let chars = self.segments().enumerate().flat_map(|(n, segment)| {
+            let nul = (n > 0).then_some('\0');
+            nul.into_iter().chain(segment.chars())
+        });
+        crate::pad_chars(f, chars)
+    }
+}
+
+impl fmt::Debug for EscapedStr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for (n, segment) in self.segments().enumerate() {
+            if n > 0 {
+                f.write_str("\\0")?;
+            }
+            crate::write_debug_chars(segment, |s| f.write_str(s))?;
+        }
+        f.write_char('"')
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($name:ident)+) => {
+        impl<$($name: EncodeKey),+> EncodeKey for ($($name,)+) {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn encode_key(&self, out: &mut Vec<u8>) {
+                let ($($name,)+) = self;
+                $($name.encode_key(out);)+
+            }
+        }
+
+        impl<'a, $($name: DecodeKey<'a>),+> DecodeKey<'a> for ($($name,)+) {
+            #[inline]
+            fn decode_key(input: &mut &'a [u8]) -> Result<Self, KeyError> {
+                Ok(($($name::decode_key(input)?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple! { A }
+impl_tuple! { A B }
+impl_tuple! { A B C }
+impl_tuple! { A B C D }
+impl_tuple! { A B C D E }
+impl_tuple! { A B C D E F }
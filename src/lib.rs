@@ -1,14 +1,80 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
-use core::{fmt, hash, ops, str};
+use core::{
+    borrow::{Borrow, BorrowMut},
+    fmt, hash, ops, str,
+};
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
 #[cfg(feature = "alloc")]
 use alloc::{borrow::Cow, boxed::Box, string::String};
-use std::cmp::Ordering;
+use core::cmp::Ordering;
+
+// A string type lives in a private module, re-exported here with its errors and char type.
+// APIs made of several items, like traits with their implementors or free functions, are public
+// modules instead, and their items are only reachable through the module.
+mod ascii;
+pub use ascii::{AsciiChar, AsciiStr, AsciiStrError, NotAscii};
+#[cfg(all(target_has_atomic = "8", target_has_atomic = "32"))]
+mod atomic;
+#[cfg(all(target_has_atomic = "8", target_has_atomic = "32"))]
+pub use atomic::AtomicStr;
+mod bstr;
+pub use bstr::BStr;
+pub mod case;
+mod chars;
+pub use chars::{CharStr, CharStrError};
+pub mod codepage;
+#[cfg(feature = "alloc")]
+mod column;
+#[cfg(feature = "alloc")]
+pub use column::StrColumn;
+mod cstr;
+pub use cstr::{CStrArray, CStrArrayError};
+#[cfg(any(
+    feature = "heapless",
+    feature = "arrayvec",
+    feature = "smol_str",
+    feature = "compact_str"
+))]
+mod interop;
+#[cfg(feature = "alloc")]
+pub mod key;
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+mod logging;
+#[cfg(feature = "hashbrown")]
+mod lookup;
+mod option;
+pub use option::OptionStr;
+#[cfg(feature = "std")]
+mod os;
+#[cfg(feature = "std")]
+pub use os::OsStrError;
+pub mod packed;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "quickcheck"))]
+mod property;
+#[cfg(feature = "rand")]
+pub mod random;
+#[cfg(feature = "derive")]
+pub mod record;
+mod secret;
+pub use secret::SecretStr;
+pub mod slice;
+mod utf16;
+pub use utf16::{Utf16Error, Utf16Str};
+
+/// Derive a fixed-layout record from a struct of [`Str<N>`] and numeric fields.
+///
+/// See [`record`] for the runtime support.
+#[cfg(feature = "derive")]
+pub use str_array_derive::FixedRecord;
+
+/// Derive a mapping between a fieldless enum and fixed-width [`Str<N>`] codes.
+#[cfg(feature = "derive")]
+pub use str_array_derive::StrCode;
 
 #[derive(Copy, Eq, PartialEq, Clone, Debug)]
 pub struct InvalidLength {
@@ -30,7 +96,25 @@ impl fmt::Display for InvalidLength {
 #[cfg(feature = "std")]
 impl std::error::Error for InvalidLength {}
 
+/// The error returned when converting a string that matches none of the codes of an enum
+/// deriving [`StrCode`].
+#[cfg(feature = "derive")]
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct UnknownCode;
+
+#[cfg(feature = "derive")]
+impl fmt::Display for UnknownCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Unknown code")
+    }
+}
+
+#[cfg(all(feature = "derive", feature = "std"))]
+impl std::error::Error for UnknownCode {}
+
 #[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct Str<const N: usize> {
     v: [u8; N],
 }
@@ -285,6 +369,151 @@ impl<const N: usize> Str<N> {
         // Safety: str is guaranteed to be valid UTF-8.
         Ok(unsafe { Self::from_utf8_unchecked(array) })
     }
+
+    /// Converts an array of bytes to a `Str`, like [`from_utf8`](Str::from_utf8), but faster
+    /// for mostly ASCII strings at the cost of not being `const`.
+    ///
+    /// The bytes are first checked for ASCII a machine word at a time, and only strings that
+    /// are not all ASCII go through the full UTF-8 validation, so those are a bit slower.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the bytes are not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let mut buf = [b' '; 1024];
+    /// buf[..5].copy_from_slice(b"hello");
+    /// let s = Str::from_utf8_fast(buf).unwrap();
+    /// assert_eq!(s.trim_end(), "hello");
+    ///
+    /// buf[1023] = 0xFF;
+    /// assert_eq!(Str::from_utf8_fast(buf).unwrap_err().valid_up_to(), 1023);
+    /// ```
+    #[inline]
+    pub fn from_utf8_fast(v: [u8; N]) -> Result<Self, str::Utf8Error> {
+        run_utf8_validation_fast(&v)?;
+        // Safety: we checked that the bytes are valid UTF-8.
+        Ok(unsafe { Self::from_utf8_unchecked_internal(v) })
+    }
+
+    /// Copies a string slice of exactly `N` bytes into a new `Str`, like
+    /// [`try_new`](Str::try_new), but with a single `copy_from_slice` instead of a byte loop, at
+    /// the cost of not being `const`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string is not exactly `N` bytes long.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let s: Str<5> = Str::try_new_fast("hello").unwrap();
+    /// assert_eq!(s, "hello");
+    ///
+    /// assert!(Str::<5>::try_new_fast("hi").is_err());
+    /// ```
+    #[inline]
+    pub fn try_new_fast(str: &str) -> Result<Self, InvalidLength> {
+        let bytes = str.as_bytes();
+        if bytes.len() != N {
+            return Err(InvalidLength {
+                expected: N,
+                actual: bytes.len(),
+            });
+        }
+        let mut array = [0u8; N];
+        array.copy_from_slice(bytes);
+        // Safety: str is guaranteed to be valid UTF-8.
+        Ok(unsafe { Self::from_utf8_unchecked_internal(array) })
+    }
+
+    /// Packs the bytes of a `Str` with `N <= 8` into a big-endian `u64`.
+    ///
+    /// The bytes are right-aligned, so for a given `N` comparing the integers orders strings
+    /// exactly like comparing them as `str`s. Using it with `N > 8` fails to build.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let a: Str<4> = Str::try_new("AAPL").unwrap();
+    /// let b: Str<4> = Str::try_new("MSFT").unwrap();
+    ///
+    /// assert_eq!(a.to_be_u64(), u64::from(u32::from_be_bytes(*b"AAPL")));
+    /// assert!(a.to_be_u64() < b.to_be_u64());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use str_array::Str;
+    /// let s: Str<9> = Str::try_new("too long!").unwrap();
+    /// s.to_be_u64();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_be_u64(&self) -> u64 {
+        const { assert!(N <= 8, "to_be_u64 requires N <= 8") };
+        pack_be_u64(&self.v)
+    }
+
+    /// Packs the bytes of a `Str` with `N <= 16` into a big-endian `u128`.
+    ///
+    /// The bytes are right-aligned, so for a given `N` comparing the integers orders strings
+    /// exactly like comparing them as `str`s. Using it with `N > 16` fails to build.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let a: Str<12> = Str::try_new("US0378331005").unwrap();
+    /// let b: Str<12> = Str::try_new("US5949181045").unwrap();
+    ///
+    /// assert!(a.to_be_u128() < b.to_be_u128());
+    /// assert_eq!(a.to_be_u128().to_be_bytes()[4..], *a.as_bytes());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_be_u128(&self) -> u128 {
+        const { assert!(N <= 16, "to_be_u128 requires N <= 16") };
+        pack_be_u128(&self.v)
+    }
+}
+
+/// Packs up to 8 bytes right-aligned into a big-endian `u64`.
+#[inline(always)]
+const fn pack_be_u64(v: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    let offset = 8 - v.len();
+    let mut i = 0;
+    while i < v.len() {
+        bytes[offset + i] = v[i];
+        i += 1;
+    }
+    u64::from_be_bytes(bytes)
+}
+
+/// Packs up to 16 bytes right-aligned into a big-endian `u128`.
+#[inline(always)]
+const fn pack_be_u128(v: &[u8]) -> u128 {
+    let mut bytes = [0u8; 16];
+    let offset = 16 - v.len();
+    let mut i = 0;
+    while i < v.len() {
+        bytes[offset + i] = v[i];
+        i += 1;
+    }
+    u128::from_be_bytes(bytes)
 }
 
 /// A new type that allows you to do `iter.collect::<TryStr<N>>()`, so it will return an error
@@ -467,6 +696,72 @@ const fn run_utf8_validation(v: &[u8]) -> Result<(), str::Utf8Error> {
     }
 }
 
+/// The runtime counterpart of [`run_utf8_validation`], with a fast path for ASCII.
+#[inline]
+fn run_utf8_validation_fast(v: &[u8]) -> Result<(), str::Utf8Error> {
+    // At runtime `is_ascii` checks whole machine words at a time, which is several times faster
+    // than a full UTF-8 validation of the same bytes.
+    if v.is_ascii() {
+        return Ok(());
+    }
+    str::from_utf8(v).map(|_| ())
+}
+
+/// Writes the chars of `s` escaped like `<str as fmt::Debug>::fmt`, without the quotes.
+///
+/// Runs of chars that need no escaping are passed to `write` as they are, so this works with
+/// any writer, not only [`fmt::Formatter`].
+pub(crate) fn write_debug_chars<E>(
+    s: &str,
+    mut write: impl FnMut(&str) -> Result<(), E>,
+) -> Result<(), E> {
+    let mut from = 0;
+    for (i, c) in s.char_indices() {
+        // `escape_debug` would also escape single quotes, which `str`'s `Debug` keeps.
+        let escape = c.escape_debug();
+        if c == '\'' || escape.len() == 1 {
+            continue;
+        }
+        write(&s[from..i])?;
+        for e in escape {
+            write(e.encode_utf8(&mut [0; 4]))?;
+        }
+        from = i + c.len_utf8();
+    }
+    write(&s[from..])
+}
+
+/// Writes `chars` like [`fmt::Formatter::pad`] writes a `&str`, for strings that are not
+/// available as one.
+pub(crate) fn pad_chars<I>(f: &mut fmt::Formatter<'_>, chars: I) -> fmt::Result
+where
+    I: Iterator<Item = char> + Clone,
+{
+    use fmt::Write;
+
+    let chars = chars.take(f.precision().unwrap_or(usize::MAX));
+    let padding = match f.width() {
+        Some(width) => width.saturating_sub(chars.clone().count()),
+        None => 0,
+    };
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+    for c in chars {
+        f.write_char(c)?;
+    }
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+    Ok(())
+}
+
 impl<const N: usize> AsRef<str> for Str<N> {
     #[inline]
     fn as_ref(&self) -> &str {
@@ -488,6 +783,53 @@ impl<const N: usize> AsRef<[u8]> for Str<N> {
     }
 }
 
+// There is no `Borrow<[u8]>`: `[u8]` hashes its length before its bytes, while `str` and
+// `Str<N>` hash their bytes followed by `0xff`, so map lookups by bytes would miss.
+impl<const N: usize> Borrow<str> for Str<N> {
+    /// Borrows the [`Str<N>`] as a `str`, to look up maps keyed by `Str<N>` with a `&str`.
+    ///
+    /// `Eq`, `Ord` and `Hash` of a [`Str<N>`] agree with those of its `str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use std::collections::{hash_map::RandomState, BTreeMap, HashMap};
+    /// use std::hash::BuildHasher;
+    /// use std::ops::Bound::{Excluded, Included};
+    /// use str_array::Str;
+    ///
+    /// let key: Str<3> = Str::try_new("abc").unwrap();
+    /// let state = RandomState::new();
+    /// assert_eq!(state.hash_one(key), state.hash_one("abc"));
+    ///
+    /// let mut hash_map = HashMap::new();
+    /// hash_map.insert(key, 1);
+    /// assert_eq!(hash_map.get("abc"), Some(&1));
+    /// // A key of the wrong length is simply not found.
+    /// assert_eq!(hash_map.get("abcd"), None);
+    ///
+    /// let mut btree_map = BTreeMap::new();
+    /// btree_map.insert(key, 1);
+    /// btree_map.insert(Str::try_new("abd").unwrap(), 2);
+    /// assert_eq!(btree_map.get("abd"), Some(&2));
+    /// let range = btree_map.range::<str, _>((Included("abc"), Excluded("abd")));
+    /// assert_eq!(range.count(), 1);
+    /// ```
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> BorrowMut<str> for Str<N> {
+    #[inline]
+    fn borrow_mut(&mut self) -> &mut str {
+        self.as_mut_str()
+    }
+}
+
 impl<const N: usize> TryFrom<&str> for Str<N> {
     type Error = InvalidLength;
     /// Try to convert a `&str` into a [`Str<N>`].
@@ -677,19 +1019,41 @@ impl_eq! { Box<str> }
 impl<const N: usize, const T: usize> PartialOrd<Str<T>> for Str<N> {
     #[inline]
     fn partial_cmp(&self, other: &Str<T>) -> Option<core::cmp::Ordering> {
-        <str as PartialOrd>::partial_cmp(self.as_ref(), other.as_ref())
+        if T == N && N <= 8 {
+            pack_be_u64(&self.v).partial_cmp(&pack_be_u64(&other.v))
+        } else if T == N && N <= 16 {
+            pack_be_u128(&self.v).partial_cmp(&pack_be_u128(&other.v))
+        } else {
+            <str as PartialOrd>::partial_cmp(self.as_ref(), other.as_ref())
+        }
     }
 }
 impl<const N: usize> Ord for Str<N> {
     #[inline]
     fn cmp(&self, other: &Self) -> Ordering {
-        <str as Ord>::cmp(self.as_ref(), other.as_ref())
+        // Short strings fit in a register, where a big-endian integer compare is a single
+        // instruction and orders like the bytes.
+        if N <= 8 {
+            pack_be_u64(&self.v).cmp(&pack_be_u64(&other.v))
+        } else if N <= 16 {
+            pack_be_u128(&self.v).cmp(&pack_be_u128(&other.v))
+        } else {
+            <str as Ord>::cmp(self.as_ref(), other.as_ref())
+        }
     }
 }
 impl<const N: usize, const T: usize> PartialEq<Str<T>> for Str<N> {
     #[inline]
     fn eq(&self, other: &Str<T>) -> bool {
-        T == N && <str as PartialEq>::eq(self.as_ref(), other.as_ref())
+        if T != N {
+            false
+        } else if N <= 8 {
+            pack_be_u64(&self.v) == pack_be_u64(&other.v)
+        } else if N <= 16 {
+            pack_be_u128(&self.v) == pack_be_u128(&other.v)
+        } else {
+            <str as PartialEq>::eq(self.as_ref(), other.as_ref())
+        }
     }
 }
 impl<const N: usize> Eq for Str<N> {}
@@ -718,6 +1082,8 @@ impl<const N: usize> fmt::Debug for Str<N> {
 }
 
 impl<const N: usize> hash::Hash for Str<N> {
+    // This must stay the hash of the `str` for `Borrow<str>`, so unlike `Eq` and `Ord` it cannot
+    // hash a packed integer for short strings.
     #[inline]
     fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
         (self.as_str()).hash(hasher)
@@ -0,0 +1,1245 @@
+//! Formatting support for embedded logging frameworks, where `core::fmt` is too heavy.
+
+#[cfg(feature = "defmt")]
+mod defmt_impls {
+    #[cfg(feature = "alloc")]
+    use crate::key::{EscapedStr, KeyError, Padded, TooLong};
+    #[cfg(feature = "std")]
+    use crate::os::OsStrError;
+    #[cfg(feature = "rand")]
+    use crate::random::InvalidAlphabet;
+    use crate::{
+        case::{CaseInsensitive, UncasedStr},
+        codepage::{EncodeError, Unrepresentable},
+        packed::{NotInAlphabet, PackAlphabet, Packed},
+        slice::SliceError,
+        AsciiStr, AsciiStrError, BStr, CStrArray, CStrArrayError, CharStr, CharStrError,
+        InvalidLength, NotAscii, OptionStr, SecretStr, Str, TryStr, Utf16Error, Utf16Str,
+    };
+    #[cfg(feature = "derive")]
+    use crate::{
+        record::{FieldError, RecordError},
+        UnknownCode,
+    };
+    use core::str::Utf8Error;
+
+    /// Writes `e` like its `Display` implementation, which `defmt` does not provide.
+    fn format_utf8_error(e: &Utf8Error, f: defmt::Formatter<'_>) {
+        match e.error_len() {
+            Some(len) => defmt::write!(
+                f,
+                "invalid utf-8 sequence of {=u8} bytes from index {=usize}",
+                len as u8,
+                e.valid_up_to()
+            ),
+            None => defmt::write!(
+                f,
+                "incomplete utf-8 byte sequence from index {=usize}",
+                e.valid_up_to()
+            ),
+        }
+    }
+
+    impl<const N: usize> defmt::Format for Str<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            // `=str` sends the bytes as is, leaving the formatting to the host.
+            defmt::write!(f, "{=str}", self.as_str())
+        }
+    }
+
+    impl<const N: usize> defmt::Format for SecretStr<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "***")
+        }
+    }
+
+    impl<const N: usize> defmt::Format for TryStr<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                TryStr::Ok(s) => defmt::write!(f, "Ok({=str})", s.as_str()),
+                TryStr::InvalidLength => defmt::write!(f, "InvalidLength"),
+            }
+        }
+    }
+
+    impl<const N: usize> defmt::Format for AsciiStr<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.as_str())
+        }
+    }
+
+    impl<const M: usize, const N: usize> defmt::Format for CharStr<M, N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.as_str())
+        }
+    }
+
+    impl<const N: usize> defmt::Format for BStr<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=[u8]:a}", self.as_bytes())
+        }
+    }
+
+    impl<const N: usize> defmt::Format for Utf16Str<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            for c in self.chars() {
+                defmt::write!(f, "{=char}", c);
+            }
+        }
+    }
+
+    impl<const N: usize> defmt::Format for CStrArray<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=[u8]:a}", self.to_bytes())
+        }
+    }
+
+    impl<const N: usize> defmt::Format for OptionStr<N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self.as_deref() {
+                Some(s) => defmt::write!(f, "Some({=str})", s),
+                None => defmt::write!(f, "None"),
+            }
+        }
+    }
+
+    impl defmt::Format for InvalidLength {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Expected string with {=usize} bytes, but got {=usize}",
+                self.expected,
+                self.actual
+            )
+        }
+    }
+
+    impl defmt::Format for NotAscii {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Expected ASCII, found a non-ASCII byte at {=usize}",
+                self.valid_up_to()
+            )
+        }
+    }
+
+    impl defmt::Format for AsciiStrError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                AsciiStrError::InvalidLength(e) => e.format(f),
+                AsciiStrError::NotAscii(e) => e.format(f),
+            }
+        }
+    }
+
+    impl defmt::Format for CharStrError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match *self {
+                CharStrError::InvalidCharCount { expected, actual } => defmt::write!(
+                    f,
+                    "Expected string with {=usize} chars, but got {=usize}",
+                    expected,
+                    actual
+                ),
+                CharStrError::CapacityExceeded { capacity, actual } => defmt::write!(
+                    f,
+                    "Expected string with at most {=usize} bytes, but got {=usize}",
+                    capacity,
+                    actual
+                ),
+            }
+        }
+    }
+
+    impl defmt::Format for Utf16Error {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Unpaired surrogate found at {=usize}",
+                self.valid_up_to()
+            )
+        }
+    }
+
+    impl defmt::Format for CStrArrayError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                CStrArrayError::NotNulTerminated => defmt::write!(f, "Expected a NUL terminator"),
+                CStrArrayError::InteriorNul { index } => {
+                    defmt::write!(f, "Unexpected NUL byte found at {=usize}", index)
+                }
+                CStrArrayError::TooLong { capacity, actual } => defmt::write!(
+                    f,
+                    "Expected string with at most {=usize} bytes, but got {=usize}",
+                    capacity,
+                    actual
+                ),
+                CStrArrayError::Utf8(e) => format_utf8_error(e, f),
+                CStrArrayError::InvalidLength(e) => e.format(f),
+            }
+        }
+    }
+
+    impl defmt::Format for SliceError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                SliceError::PartialRecord { records, len } => defmt::write!(
+                    f,
+                    "Expected whole records, but got {=usize} and a partial record of {=usize} bytes",
+                    records,
+                    len
+                ),
+                SliceError::InvalidRecord { index, error } => {
+                    defmt::write!(f, "Invalid record at index {=usize}: ", index);
+                    format_utf8_error(error, f)
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl defmt::Format for KeyError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                KeyError::UnexpectedEnd => defmt::write!(f, "Unexpected end of key"),
+                KeyError::InvalidEscape => defmt::write!(f, "Invalid escape in key string"),
+                KeyError::InvalidPadding => defmt::write!(f, "Invalid padding in key string"),
+                KeyError::Utf8(e) => format_utf8_error(e, f),
+                KeyError::TrailingBytes { len } => {
+                    defmt::write!(f, "Expected end of key, but got {=usize} more bytes", len)
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl defmt::Format for TooLong {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Expected string with at most {=usize} bytes, but got {=usize}",
+                self.capacity(),
+                self.actual()
+            )
+        }
+    }
+    impl<A: PackAlphabet, const N: usize, const B: usize> defmt::Format for Packed<A, N, B> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.unpack().as_str())
+        }
+    }
+
+    impl<S: defmt::Format, F> defmt::Format for CaseInsensitive<S, F> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            self.get().format(f)
+        }
+    }
+
+    impl<F> defmt::Format for UncasedStr<F> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.as_str())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const N: usize> defmt::Format for Padded<'_, N> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "{=str}", self.as_str())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl defmt::Format for EscapedStr<'_> {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            for (n, segment) in self.segments().enumerate() {
+                if n > 0 {
+                    defmt::write!(f, "{=char}", '\0');
+                }
+                defmt::write!(f, "{=str}", segment);
+            }
+        }
+    }
+
+    impl defmt::Format for NotInAlphabet {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Expected a character of the alphabet, found another byte at {=usize}",
+                self.valid_up_to()
+            )
+        }
+    }
+
+    impl defmt::Format for Unrepresentable {
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "Cannot encode ");
+            for (n, &(index, ch)) in self.chars().iter().enumerate() {
+                if n > 0 {
+                    defmt::write!(f, ", ");
+                }
+                defmt::write!(f, "'{=char}' at {=usize}", ch, index);
+            }
+            let unlisted = self.count() - self.chars().len();
+            if unlisted > 0 {
+                defmt::write!(f, " and {=usize} more chars", unlisted);
+            }
+            defmt::write!(f, " in the code page")
+        }
+    }
+
+    impl defmt::Format for EncodeError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                EncodeError::InvalidCharCount { expected, actual } => defmt::write!(
+                    f,
+                    "Expected string with {=usize} chars, but got {=usize}",
+                    expected,
+                    actual
+                ),
+                EncodeError::Unrepresentable(e) => e.format(f),
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl defmt::Format for FieldError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                FieldError::Utf8(e) => format_utf8_error(e, f),
+                FieldError::NotAscii => defmt::write!(f, "non-ASCII byte in ASCII field"),
+                FieldError::InvalidDigit => defmt::write!(f, "invalid digit in numeric field"),
+                FieldError::Overflow => defmt::write!(f, "number does not fit in the field"),
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl defmt::Format for RecordError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(
+                f,
+                "Invalid field `{=str}` at offset {=usize}: {}",
+                self.field(),
+                self.offset(),
+                self.error()
+            )
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl defmt::Format for UnknownCode {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            defmt::write!(f, "Unknown code")
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl defmt::Format for OsStrError {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                OsStrError::NotUtf8 => defmt::write!(f, "Expected a valid UTF-8 platform string"),
+                OsStrError::InvalidLength(e) => e.format(f),
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    impl defmt::Format for InvalidAlphabet {
+        #[inline]
+        fn format(&self, f: defmt::Formatter<'_>) {
+            match self {
+                InvalidAlphabet::Empty => defmt::write!(f, "Alphabet is empty"),
+                InvalidAlphabet::MixedWidths => {
+                    defmt::write!(f, "Alphabet characters have different UTF-8 widths")
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ufmt")]
+mod ufmt_impls {
+    #[cfg(feature = "alloc")]
+    use crate::key::{EscapedStr, KeyError, Padded, TooLong};
+    #[cfg(feature = "std")]
+    use crate::os::OsStrError;
+    #[cfg(feature = "rand")]
+    use crate::random::InvalidAlphabet;
+    use crate::{
+        case::{CaseInsensitive, UncasedStr},
+        codepage::{EncodeError, Unrepresentable},
+        packed::{NotInAlphabet, PackAlphabet, Packed},
+        slice::SliceError,
+        AsciiStr, AsciiStrError, BStr, CStrArray, CStrArrayError, CharStr, CharStrError,
+        InvalidLength, NotAscii, OptionStr, SecretStr, Str, TryStr, Utf16Error, Utf16Str,
+    };
+    #[cfg(feature = "derive")]
+    use crate::{
+        record::{FieldError, RecordError},
+        UnknownCode,
+    };
+    use core::str::Utf8Error;
+    use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+    /// Writes `s` quoted and escaped, like `<str as core::fmt::Debug>::fmt`.
+    fn debug_str<W: uWrite + ?Sized>(s: &str, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_char('"')?;
+        crate::write_debug_chars(s, |s| f.write_str(s))?;
+        f.write_char('"')
+    }
+
+    /// Writes `b` as `\xNN`, like the invalid bytes of `BStr`'s `Debug`.
+    fn debug_byte<W: uWrite + ?Sized>(b: u8, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        f.write_str("\\x")?;
+        f.write_char(HEX[usize::from(b >> 4)] as char)?;
+        f.write_char(HEX[usize::from(b & 0xF)] as char)
+    }
+
+    /// Writes `c` quoted and escaped, like `<char as core::fmt::Debug>::fmt`.
+    fn debug_char<W: uWrite + ?Sized>(c: char, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+        f.write_char('\'')?;
+        if c == '\'' {
+            // Unlike in a `str`, a single quote is escaped in a `char`.
+            f.write_str("\\'")?;
+        } else {
+            crate::write_debug_chars(c.encode_utf8(&mut [0; 4]), |s| f.write_str(s))?;
+        }
+        f.write_char('\'')
+    }
+
+    /// Writes `e` like its `Display` implementation, which `ufmt` does not provide.
+    fn display_utf8_error<W: uWrite + ?Sized>(
+        e: &Utf8Error,
+        f: &mut Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        match e.error_len() {
+            Some(len) => ufmt::uwrite!(
+                f,
+                "invalid utf-8 sequence of {} bytes from index {}",
+                len,
+                e.valid_up_to()
+            ),
+            None => ufmt::uwrite!(
+                f,
+                "incomplete utf-8 byte sequence from index {}",
+                e.valid_up_to()
+            ),
+        }
+    }
+
+    /// Writes `e` like its `Debug` implementation, which `ufmt` does not provide.
+    fn debug_utf8_error<W: uWrite + ?Sized>(
+        e: &Utf8Error,
+        f: &mut Formatter<'_, W>,
+    ) -> Result<(), W::Error> {
+        f.debug_struct("Utf8Error")?
+            .field("valid_up_to", &e.valid_up_to())?
+            .field("error_len", &e.error_len().map(|len| len as u8))?
+            .finish()
+    }
+
+    impl<const N: usize> uDisplay for Str<N> {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let s: Str<5> = Str::try_new("hello").unwrap();
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{} {:?}", s, s).unwrap();
+        /// assert_eq!(out, "hello \"hello\"");
+        /// ```
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl<const N: usize> uDebug for Str<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.as_str(), f)
+        }
+    }
+
+    impl<const N: usize> uDisplay for SecretStr<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str("***")
+        }
+    }
+
+    impl<const N: usize> uDebug for SecretStr<N> {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::SecretStr;
+        /// let pin: SecretStr<4> = SecretStr::try_new("1234").unwrap();
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{} {:?}", pin, pin).unwrap();
+        /// assert_eq!(out, "*** SecretStr<4>(***)");
+        /// ```
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(f, "SecretStr<{}>(***)", N)
+        }
+    }
+
+    impl<const N: usize> uDebug for TryStr<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                TryStr::Ok(s) => f.debug_tuple("Ok")?.field(s)?.finish(),
+                TryStr::InvalidLength => f.write_str("InvalidLength"),
+            }
+        }
+    }
+
+    impl<const N: usize> uDisplay for AsciiStr<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl<const N: usize> uDebug for AsciiStr<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.as_str(), f)
+        }
+    }
+
+    impl<const M: usize, const N: usize> uDisplay for CharStr<M, N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl<const M: usize, const N: usize> uDebug for CharStr<M, N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.as_str(), f)
+        }
+    }
+
+    impl<const N: usize> uDisplay for BStr<N> {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            for chunk in self.as_bytes().utf8_chunks() {
+                f.write_str(chunk.valid())?;
+                if !chunk.invalid().is_empty() {
+                    f.write_char('\u{FFFD}')?;
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl<const N: usize> uDebug for BStr<N> {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::BStr;
+        /// let s = BStr::new(*b"a\"\xFF");
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{} {:?}", s, s).unwrap();
+        /// assert_eq!(out, "a\"\u{FFFD} \"a\\\"\\xFF\"");
+        /// ```
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_char('"')?;
+            for chunk in self.as_bytes().utf8_chunks() {
+                crate::write_debug_chars(chunk.valid(), |s| f.write_str(s))?;
+                for &b in chunk.invalid() {
+                    debug_byte(b, f)?;
+                }
+            }
+            f.write_char('"')
+        }
+    }
+
+    impl<const N: usize> uDisplay for Utf16Str<N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            self.chars().try_for_each(|c| f.write_char(c))
+        }
+    }
+
+    impl<const N: usize> uDebug for Utf16Str<N> {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_char('"')?;
+            for c in self.chars() {
+                crate::write_debug_chars(c.encode_utf8(&mut [0; 4]), |s| f.write_str(s))?;
+            }
+            f.write_char('"')
+        }
+    }
+
+    impl<const N: usize> uDebug for CStrArray<N> {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            // Like `CStr`'s `Debug`, which escapes every byte that is not printable ASCII.
+            f.write_char('"')?;
+            for b in self.to_bytes().escape_ascii() {
+                f.write_char(char::from(b))?;
+            }
+            f.write_char('"')
+        }
+    }
+
+    impl<const N: usize> uDebug for OptionStr<N> {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::{OptionStr, Str};
+        /// let code: OptionStr<3> = OptionStr::some(Str::try_new("EUR").unwrap());
+        /// let missing: OptionStr<3> = OptionStr::none();
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{:?} {:?}", code, missing).unwrap();
+        /// assert_eq!(out, "Some(\"EUR\") None");
+        /// ```
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self.as_ref() {
+                Some(s) => f.debug_tuple("Some")?.field(s)?.finish(),
+                None => f.write_str("None"),
+            }
+        }
+    }
+
+    impl uDisplay for InvalidLength {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let e = Str::<3>::try_new("hello").unwrap_err();
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{}", e).unwrap();
+        /// assert_eq!(out, e.to_string());
+        /// ```
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(
+                f,
+                "Expected string with {} bytes, but got {}",
+                self.expected,
+                self.actual
+            )
+        }
+    }
+
+    impl uDebug for InvalidLength {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("InvalidLength")?
+                .field("expected", &self.expected)?
+                .field("actual", &self.actual)?
+                .finish()
+        }
+    }
+
+    impl uDisplay for NotAscii {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(
+                f,
+                "Expected ASCII, found a non-ASCII byte at {}",
+                self.valid_up_to()
+            )
+        }
+    }
+
+    impl uDebug for NotAscii {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("NotAscii")?
+                .field("index", &self.valid_up_to())?
+                .finish()
+        }
+    }
+
+    impl uDisplay for AsciiStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                AsciiStrError::InvalidLength(e) => uDisplay::fmt(e, f),
+                AsciiStrError::NotAscii(e) => uDisplay::fmt(e, f),
+            }
+        }
+    }
+
+    impl uDebug for AsciiStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                AsciiStrError::InvalidLength(e) => {
+                    f.debug_tuple("InvalidLength")?.field(e)?.finish()
+                }
+                AsciiStrError::NotAscii(e) => f.debug_tuple("NotAscii")?.field(e)?.finish(),
+            }
+        }
+    }
+
+    impl uDisplay for CharStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match *self {
+                CharStrError::InvalidCharCount { expected, actual } => ufmt::uwrite!(
+                    f,
+                    "Expected string with {} chars, but got {}",
+                    expected,
+                    actual
+                ),
+                CharStrError::CapacityExceeded { capacity, actual } => ufmt::uwrite!(
+                    f,
+                    "Expected string with at most {} bytes, but got {}",
+                    capacity,
+                    actual
+                ),
+            }
+        }
+    }
+
+    impl uDebug for CharStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                CharStrError::InvalidCharCount { expected, actual } => f
+                    .debug_struct("InvalidCharCount")?
+                    .field("expected", expected)?
+                    .field("actual", actual)?
+                    .finish(),
+                CharStrError::CapacityExceeded { capacity, actual } => f
+                    .debug_struct("CapacityExceeded")?
+                    .field("capacity", capacity)?
+                    .field("actual", actual)?
+                    .finish(),
+            }
+        }
+    }
+
+    impl uDisplay for Utf16Error {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(f, "Unpaired surrogate found at {}", self.valid_up_to())
+        }
+    }
+
+    impl uDebug for Utf16Error {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("Utf16Error")?
+                .field("index", &self.valid_up_to())?
+                .finish()
+        }
+    }
+
+    impl uDisplay for CStrArrayError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                CStrArrayError::NotNulTerminated => f.write_str("Expected a NUL terminator"),
+                CStrArrayError::InteriorNul { index } => {
+                    ufmt::uwrite!(f, "Unexpected NUL byte found at {}", index)
+                }
+                CStrArrayError::TooLong { capacity, actual } => ufmt::uwrite!(
+                    f,
+                    "Expected string with at most {} bytes, but got {}",
+                    capacity,
+                    actual
+                ),
+                CStrArrayError::Utf8(e) => display_utf8_error(e, f),
+                CStrArrayError::InvalidLength(e) => uDisplay::fmt(e, f),
+            }
+        }
+    }
+
+    impl uDebug for CStrArrayError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                CStrArrayError::NotNulTerminated => f.write_str("NotNulTerminated"),
+                CStrArrayError::InteriorNul { index } => f
+                    .debug_struct("InteriorNul")?
+                    .field("index", index)?
+                    .finish(),
+                CStrArrayError::TooLong { capacity, actual } => f
+                    .debug_struct("TooLong")?
+                    .field("capacity", capacity)?
+                    .field("actual", actual)?
+                    .finish(),
+                CStrArrayError::Utf8(e) => {
+                    f.write_str("Utf8(")?;
+                    debug_utf8_error(e, f)?;
+                    f.write_char(')')
+                }
+                CStrArrayError::InvalidLength(e) => {
+                    f.debug_tuple("InvalidLength")?.field(e)?.finish()
+                }
+            }
+        }
+    }
+
+    impl uDisplay for SliceError {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::Str;
+        /// let e = Str::<2>::slice_from_bytes(b"abc\xFF").unwrap_err();
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{}", e).unwrap();
+        /// assert_eq!(out, e.to_string());
+        /// ```
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                SliceError::PartialRecord { records, len } => ufmt::uwrite!(
+                    f,
+                    "Expected whole records, but got {} and a partial record of {} bytes",
+                    records,
+                    len
+                ),
+                SliceError::InvalidRecord { index, error } => {
+                    ufmt::uwrite!(f, "Invalid record at index {}: ", index)?;
+                    display_utf8_error(error, f)
+                }
+            }
+        }
+    }
+
+    impl uDebug for SliceError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                SliceError::PartialRecord { records, len } => f
+                    .debug_struct("PartialRecord")?
+                    .field("records", records)?
+                    .field("len", len)?
+                    .finish(),
+                SliceError::InvalidRecord { index, error } => {
+                    ufmt::uwrite!(f, "InvalidRecord {{ index: {}, error: ", index)?;
+                    debug_utf8_error(error, f)?;
+                    f.write_str(" }")
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDisplay for KeyError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                KeyError::UnexpectedEnd => f.write_str("Unexpected end of key"),
+                KeyError::InvalidEscape => f.write_str("Invalid escape in key string"),
+                KeyError::InvalidPadding => f.write_str("Invalid padding in key string"),
+                KeyError::Utf8(e) => display_utf8_error(e, f),
+                KeyError::TrailingBytes { len } => {
+                    ufmt::uwrite!(f, "Expected end of key, but got {} more bytes", len)
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDebug for KeyError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                KeyError::UnexpectedEnd => f.write_str("UnexpectedEnd"),
+                KeyError::InvalidEscape => f.write_str("InvalidEscape"),
+                KeyError::InvalidPadding => f.write_str("InvalidPadding"),
+                KeyError::Utf8(e) => {
+                    f.write_str("Utf8(")?;
+                    debug_utf8_error(e, f)?;
+                    f.write_char(')')
+                }
+                KeyError::TrailingBytes { len } => {
+                    f.debug_struct("TrailingBytes")?.field("len", len)?.finish()
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDisplay for TooLong {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(
+                f,
+                "Expected string with at most {} bytes, but got {}",
+                self.capacity(),
+                self.actual()
+            )
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDebug for TooLong {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("TooLong")?
+                .field("capacity", &self.capacity())?
+                .field("actual", &self.actual())?
+                .finish()
+        }
+    }
+
+    impl<A: PackAlphabet, const N: usize, const B: usize> uDisplay for Packed<A, N, B> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.unpack().as_str())
+        }
+    }
+
+    impl<A: PackAlphabet, const N: usize, const B: usize> uDebug for Packed<A, N, B> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.unpack().as_str(), f)
+        }
+    }
+
+    impl<S: uDisplay, F> uDisplay for CaseInsensitive<S, F> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            uDisplay::fmt(self.get(), f)
+        }
+    }
+
+    impl<S: uDebug, F> uDebug for CaseInsensitive<S, F> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            uDebug::fmt(self.get(), f)
+        }
+    }
+
+    impl<F> uDisplay for UncasedStr<F> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+
+    impl<F> uDebug for UncasedStr<F> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.as_str(), f)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const N: usize> uDisplay for Padded<'_, N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str(self.as_str())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl<const N: usize> uDebug for Padded<'_, N> {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            debug_str(self.as_str(), f)
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDisplay for EscapedStr<'_> {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            for (n, segment) in self.segments().enumerate() {
+                if n > 0 {
+                    f.write_char('\0')?;
+                }
+                f.write_str(segment)?;
+            }
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl uDebug for EscapedStr<'_> {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::key::{self, EncodeKey, EscapedStr};
+        /// let mut buf = Vec::new();
+        /// "a\0b".encode_key(&mut buf);
+        /// let s: EscapedStr = key::decode(&buf).unwrap();
+        ///
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{:?}", s).unwrap();
+        /// assert_eq!(out, format!("{:?}", s));
+        /// ```
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_char('"')?;
+            for (n, segment) in self.segments().enumerate() {
+                if n > 0 {
+                    f.write_str("\\0")?;
+                }
+                crate::write_debug_chars(segment, |s| f.write_str(s))?;
+            }
+            f.write_char('"')
+        }
+    }
+
+    impl uDisplay for NotInAlphabet {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(
+                f,
+                "Expected a character of the alphabet, found another byte at {}",
+                self.valid_up_to()
+            )
+        }
+    }
+
+    impl uDebug for NotInAlphabet {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.debug_struct("NotInAlphabet")?
+                .field("index", &self.valid_up_to())?
+                .finish()
+        }
+    }
+
+    impl uDisplay for Unrepresentable {
+        /// # Examples
+        ///
+        /// Basic usage:
+        ///
+        /// ```
+        /// # use str_array::codepage::{EncodeError, CP500};
+        /// let Err(EncodeError::Unrepresentable(e)) = CP500.encode::<4>("€1–2") else {
+        ///     panic!()
+        /// };
+        /// let mut out = String::new();
+        /// ufmt::uwrite!(out, "{}", e).unwrap();
+        /// assert_eq!(out, e.to_string());
+        /// ```
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str("Cannot encode ")?;
+            for (n, &(index, ch)) in self.chars().iter().enumerate() {
+                if n > 0 {
+                    f.write_str(", ")?;
+                }
+                debug_char(ch, f)?;
+                ufmt::uwrite!(f, " at {}", index)?;
+            }
+            let unlisted = self.count() - self.chars().len();
+            if unlisted > 0 {
+                ufmt::uwrite!(f, " and {} more chars", unlisted)?;
+            }
+            f.write_str(" in the code page")
+        }
+    }
+
+    impl uDebug for Unrepresentable {
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str("Unrepresentable { chars: [")?;
+            for (n, &(index, ch)) in self.chars().iter().enumerate() {
+                if n > 0 {
+                    f.write_str(", ")?;
+                }
+                ufmt::uwrite!(f, "({}, ", index)?;
+                debug_char(ch, f)?;
+                f.write_char(')')?;
+            }
+            ufmt::uwrite!(f, "], count: {} }}", self.count())
+        }
+    }
+
+    impl uDisplay for EncodeError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                EncodeError::InvalidCharCount { expected, actual } => ufmt::uwrite!(
+                    f,
+                    "Expected string with {} chars, but got {}",
+                    expected,
+                    actual
+                ),
+                EncodeError::Unrepresentable(e) => uDisplay::fmt(e, f),
+            }
+        }
+    }
+
+    impl uDebug for EncodeError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                EncodeError::InvalidCharCount { expected, actual } => f
+                    .debug_struct("InvalidCharCount")?
+                    .field("expected", expected)?
+                    .field("actual", actual)?
+                    .finish(),
+                EncodeError::Unrepresentable(e) => {
+                    f.debug_tuple("Unrepresentable")?.field(e)?.finish()
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDisplay for FieldError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                FieldError::Utf8(e) => display_utf8_error(e, f),
+                FieldError::NotAscii => f.write_str("non-ASCII byte in ASCII field"),
+                FieldError::InvalidDigit => f.write_str("invalid digit in numeric field"),
+                FieldError::Overflow => f.write_str("number does not fit in the field"),
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDebug for FieldError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                FieldError::Utf8(e) => {
+                    f.write_str("Utf8(")?;
+                    debug_utf8_error(e, f)?;
+                    f.write_char(')')
+                }
+                FieldError::NotAscii => f.write_str("NotAscii"),
+                FieldError::InvalidDigit => f.write_str("InvalidDigit"),
+                FieldError::Overflow => f.write_str("Overflow"),
+            }
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDisplay for RecordError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            ufmt::uwrite!(
+                f,
+                "Invalid field `{}` at offset {}: {}",
+                self.field(),
+                self.offset(),
+                self.error()
+            )
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDebug for RecordError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            // `ufmt` has no `uDebug` for `str`, so the field name is written by hand.
+            f.write_str("RecordError { field: ")?;
+            debug_str(self.field(), f)?;
+            ufmt::uwrite!(
+                f,
+                ", offset: {}, error: {:?} }}",
+                self.offset(),
+                self.error()
+            )
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDisplay for UnknownCode {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str("Unknown code")
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    impl uDebug for UnknownCode {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            f.write_str("UnknownCode")
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl uDisplay for OsStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                OsStrError::NotUtf8 => f.write_str("Expected a valid UTF-8 platform string"),
+                OsStrError::InvalidLength(e) => uDisplay::fmt(e, f),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl uDebug for OsStrError {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                OsStrError::NotUtf8 => f.write_str("NotUtf8"),
+                OsStrError::InvalidLength(e) => f.debug_tuple("InvalidLength")?.field(e)?.finish(),
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    impl uDisplay for InvalidAlphabet {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                InvalidAlphabet::Empty => f.write_str("Alphabet is empty"),
+                InvalidAlphabet::MixedWidths => {
+                    f.write_str("Alphabet characters have different UTF-8 widths")
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    impl uDebug for InvalidAlphabet {
+        #[inline]
+        fn fmt<W: uWrite + ?Sized>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error> {
+            match self {
+                InvalidAlphabet::Empty => f.write_str("Empty"),
+                InvalidAlphabet::MixedWidths => f.write_str("MixedWidths"),
+            }
+        }
+    }
+}
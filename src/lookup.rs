@@ -0,0 +1,55 @@
+//! Heterogeneous lookups in `hashbrown` maps keyed by [`Str<N>`].
+
+use crate::Str;
+use hashbrown::Equivalent;
+
+// `str` is already covered through `Borrow<str>`, these let owned strings look up a map
+// keyed by `Str<N>` without borrowing them first.
+
+impl<const N: usize> Equivalent<Str<N>> for &str {
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let mut map = hashbrown::HashMap::new();
+    /// map.insert(Str::<3>::try_new("abc").unwrap(), 1);
+    ///
+    /// assert_eq!(map.get("abc"), Some(&1));
+    /// assert_eq!(map.get(&"abc"), Some(&1));
+    /// assert_eq!(map.get(&String::from("abc")), Some(&1));
+    /// assert_eq!(map.get("abcd"), None);
+    /// ```
+    #[inline]
+    fn equivalent(&self, key: &Str<N>) -> bool {
+        *self == key.as_str()
+    }
+}
+
+#[cfg(feature = "alloc")]
+mod alloc_impls {
+    use super::*;
+    use alloc::{borrow::Cow, boxed::Box, string::String};
+
+    impl<const N: usize> Equivalent<Str<N>> for String {
+        #[inline]
+        fn equivalent(&self, key: &Str<N>) -> bool {
+            self.as_str() == key.as_str()
+        }
+    }
+
+    impl<const N: usize> Equivalent<Str<N>> for Box<str> {
+        #[inline]
+        fn equivalent(&self, key: &Str<N>) -> bool {
+            **self == *key.as_str()
+        }
+    }
+
+    impl<const N: usize> Equivalent<Str<N>> for Cow<'_, str> {
+        #[inline]
+        fn equivalent(&self, key: &Str<N>) -> bool {
+            **self == *key.as_str()
+        }
+    }
+}
@@ -0,0 +1,257 @@
+use crate::Str;
+use core::{cmp::Ordering, fmt, hash};
+
+/// The byte marking a [`OptionStr`] as `None`, which can never start valid UTF-8.
+const NONE: u8 = 0xFF;
+
+/// An optional [`Str<N>`] that takes exactly `N` bytes.
+///
+/// `Option<Str<N>>` needs an extra byte for its discriminant. `OptionStr` instead marks `None`
+/// with a first byte of `0xFF`, which valid UTF-8 never contains, so arrays of optional codes
+/// stay as compact as arrays of codes. `N` must be at least 1, which is checked at compile time.
+///
+/// It converts to and from `Option<Str<N>>`, and compares, orders and hashes like it.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{OptionStr, Str};
+/// assert_eq!(size_of::<OptionStr<3>>(), 3);
+/// assert_eq!(size_of::<Option<str_array::Str<3>>>(), 4);
+///
+/// let code: OptionStr<3> = OptionStr::some(Str::try_new("EUR").unwrap());
+/// assert_eq!(code.map(|s| s.to_ascii_lowercase()).as_deref(), Some("eur"));
+///
+/// let missing: OptionStr<3> = OptionStr::none();
+/// assert_eq!(missing.unwrap_or(Str::try_new("USD").unwrap()), "USD");
+/// assert_eq!(Option::from(missing), None::<Str<3>>);
+/// ```
+///
+/// An empty string has no byte to mark `None` with, so it is rejected at compile time:
+///
+/// ```compile_fail
+/// # use str_array::OptionStr;
+/// let empty: OptionStr<0> = OptionStr::none();
+/// ```
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct OptionStr<const N: usize> {
+    // Valid UTF-8 unless the first byte is `NONE`.
+    v: [u8; N],
+}
+
+impl<const N: usize> OptionStr<N> {
+    /// Returns an `OptionStr` holding `s`.
+    #[inline]
+    #[must_use]
+    pub const fn some(s: Str<N>) -> Self {
+        const { assert!(N > 0, "OptionStr<N> requires N > 0") };
+        Self { v: s.v }
+    }
+
+    /// Returns an empty `OptionStr`.
+    #[inline]
+    #[must_use]
+    pub const fn none() -> Self {
+        const { assert!(N > 0, "OptionStr<N> requires N > 0") };
+        let mut v = [0u8; N];
+        v[0] = NONE;
+        Self { v }
+    }
+
+    /// Converts an `Option<Str<N>>` into an `OptionStr`.
+    #[inline]
+    #[must_use]
+    pub const fn from_option(s: Option<Str<N>>) -> Self {
+        match s {
+            Some(s) => Self::some(s),
+            None => Self::none(),
+        }
+    }
+
+    /// Returns `true` if this holds a string.
+    #[inline]
+    #[must_use]
+    pub const fn is_some(&self) -> bool {
+        !self.is_none()
+    }
+
+    /// Returns `true` if this is empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_none(&self) -> bool {
+        self.v[0] == NONE
+    }
+
+    /// Converts this into an `Option<Str<N>>`.
+    #[inline]
+    #[must_use]
+    pub const fn get(self) -> Option<Str<N>> {
+        if self.is_none() {
+            None
+        } else {
+            // Safety: the bytes are valid UTF-8 when the first one is not `NONE`.
+            Some(unsafe { Str::from_utf8_unchecked(self.v) })
+        }
+    }
+
+    /// Returns a reference to the string, if any.
+    #[inline]
+    #[must_use]
+    pub const fn as_ref(&self) -> Option<&Str<N>> {
+        if self.is_none() {
+            None
+        } else {
+            // Safety: `Str<N>` is a transparent `[u8; N]`, and the bytes are valid UTF-8 when
+            // the first one is not `NONE`.
+            Some(unsafe { &*(&self.v as *const [u8; N]).cast::<Str<N>>() })
+        }
+    }
+
+    /// Returns a mutable reference to the string, if any.
+    #[inline]
+    #[must_use]
+    pub const fn as_mut(&mut self) -> Option<&mut Str<N>> {
+        if self.is_none() {
+            None
+        } else {
+            // Safety: as in `as_ref`. Writes through the `&mut Str<N>` keep the bytes valid
+            // UTF-8, so the first one cannot become `NONE`.
+            Some(unsafe { &mut *(&mut self.v as *mut [u8; N]).cast::<Str<N>>() })
+        }
+    }
+
+    /// Returns the string as a `&str`, if any.
+    #[inline]
+    #[must_use]
+    pub const fn as_deref(&self) -> Option<&str> {
+        match self.as_ref() {
+            Some(s) => Some(s.as_str()),
+            None => None,
+        }
+    }
+
+    /// Maps the string with `f`, if any.
+    #[inline]
+    pub fn map<U, F: FnOnce(Str<N>) -> U>(self, f: F) -> Option<U> {
+        self.get().map(f)
+    }
+
+    /// Returns the string, or `default` if this is empty.
+    #[inline]
+    #[must_use]
+    pub const fn unwrap_or(self, default: Str<N>) -> Str<N> {
+        match self.get() {
+            Some(s) => s,
+            None => default,
+        }
+    }
+
+    /// Returns the string, or computes it with `f` if this is empty.
+    #[inline]
+    pub fn unwrap_or_else<F: FnOnce() -> Str<N>>(self, f: F) -> Str<N> {
+        self.get().unwrap_or_else(f)
+    }
+
+    /// Returns the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is empty.
+    #[inline]
+    #[track_caller]
+    #[must_use]
+    pub const fn unwrap(self) -> Str<N> {
+        match self.get() {
+            Some(s) => s,
+            None => panic!("called `OptionStr::unwrap()` on a `None` value"),
+        }
+    }
+
+    /// Takes the string out, leaving this empty.
+    #[inline]
+    pub const fn take(&mut self) -> Option<Str<N>> {
+        let s = self.get();
+        *self = Self::none();
+        s
+    }
+
+    /// Replaces the string with `s`, returning the previous one.
+    #[inline]
+    pub const fn replace(&mut self, s: Str<N>) -> Option<Str<N>> {
+        let old = self.get();
+        *self = Self::some(s);
+        old
+    }
+}
+
+impl<const N: usize> Default for OptionStr<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<const N: usize> From<Str<N>> for OptionStr<N> {
+    #[inline]
+    fn from(v: Str<N>) -> Self {
+        Self::some(v)
+    }
+}
+
+impl<const N: usize> From<Option<Str<N>>> for OptionStr<N> {
+    #[inline]
+    fn from(v: Option<Str<N>>) -> Self {
+        Self::from_option(v)
+    }
+}
+
+impl<const N: usize> From<OptionStr<N>> for Option<Str<N>> {
+    #[inline]
+    fn from(v: OptionStr<N>) -> Self {
+        v.get()
+    }
+}
+
+impl<const N: usize> PartialEq for OptionStr<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+impl<const N: usize> Eq for OptionStr<N> {}
+
+impl<const N: usize> PartialEq<Option<Str<N>>> for OptionStr<N> {
+    #[inline]
+    fn eq(&self, other: &Option<Str<N>>) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<const N: usize> PartialOrd for OptionStr<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for OptionStr<N> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_ref().cmp(&other.as_ref())
+    }
+}
+
+impl<const N: usize> hash::Hash for OptionStr<N> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.as_ref().hash(hasher)
+    }
+}
+
+impl<const N: usize> fmt::Debug for OptionStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ref(), f)
+    }
+}
@@ -0,0 +1,144 @@
+//! Conversions between [`Str<N>`] and the platform strings of `std`, so it can be used as a
+//! file name with `std::fs` and `Path::join`.
+
+use crate::{InvalidLength, Str};
+use std::{
+    ffi::{OsStr, OsString},
+    fmt,
+    path::{Path, PathBuf},
+};
+
+/// The error returned when converting an [`OsStr`] or a [`Path`] into a [`Str<N>`].
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum OsStrError {
+    /// The platform string is not valid UTF-8.
+    NotUtf8,
+    /// The string's length is not exactly `N`.
+    InvalidLength(InvalidLength),
+}
+
+impl fmt::Display for OsStrError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OsStrError::NotUtf8 => f.write_str("Expected a valid UTF-8 platform string"),
+            OsStrError::InvalidLength(e) => fmt::Display::fmt(e, f),
+        }
+    }
+}
+
+impl std::error::Error for OsStrError {}
+
+impl From<InvalidLength> for OsStrError {
+    #[inline]
+    fn from(e: InvalidLength) -> Self {
+        OsStrError::InvalidLength(e)
+    }
+}
+
+impl<const N: usize> AsRef<OsStr> for Str<N> {
+    #[inline]
+    fn as_ref(&self) -> &OsStr {
+        OsStr::new(self.as_str())
+    }
+}
+
+impl<const N: usize> AsRef<Path> for Str<N> {
+    /// Borrows the [`Str<N>`] as a `Path`, to use it with `std::fs` and `Path::join`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use std::path::Path;
+    /// # use str_array::Str;
+    /// let name: Str<12> = Str::try_new("0f3a9c.cache").unwrap();
+    /// let path = Path::new("/var/cache/app").join(name);
+    /// assert_eq!(path, Path::new("/var/cache/app/0f3a9c.cache"));
+    ///
+    /// let stem: Str<6> = Str::try_from(path.file_stem().unwrap()).unwrap();
+    /// assert_eq!(stem, "0f3a9c");
+    /// ```
+    #[inline]
+    fn as_ref(&self) -> &Path {
+        Path::new(self.as_str())
+    }
+}
+
+impl<const N: usize> TryFrom<&OsStr> for Str<N> {
+    type Error = OsStrError;
+    /// Try to convert a `&OsStr` into a [`Str<N>`].
+    ///
+    /// The result will fail if the string is not valid UTF-8, or if its length is not exactly
+    /// `N`.
+    #[inline]
+    fn try_from(v: &OsStr) -> Result<Self, Self::Error> {
+        match v.to_str() {
+            Some(s) => Ok(Self::try_new(s)?),
+            None => Err(OsStrError::NotUtf8),
+        }
+    }
+}
+
+impl<const N: usize> TryFrom<&Path> for Str<N> {
+    type Error = OsStrError;
+    /// Try to convert a `&Path` into a [`Str<N>`].
+    ///
+    /// The result will fail if the path is not valid UTF-8, or if its length is not exactly `N`.
+    #[inline]
+    fn try_from(v: &Path) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_os_str())
+    }
+}
+
+impl<const N: usize> TryFrom<OsString> for Str<N> {
+    type Error = OsStrError;
+    /// Try to convert an `OsString` into a [`Str<N>`].
+    ///
+    /// The result will fail if the string is not valid UTF-8, or if its length is not exactly
+    /// `N`.
+    #[inline]
+    fn try_from(v: OsString) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_os_str())
+    }
+}
+
+impl<const N: usize> TryFrom<PathBuf> for Str<N> {
+    type Error = OsStrError;
+    /// Try to convert a `PathBuf` into a [`Str<N>`].
+    ///
+    /// The result will fail if the path is not valid UTF-8, or if its length is not exactly `N`.
+    #[inline]
+    fn try_from(v: PathBuf) -> Result<Self, Self::Error> {
+        Self::try_from(v.as_os_str())
+    }
+}
+
+impl<const N: usize> From<Str<N>> for OsString {
+    #[inline]
+    fn from(v: Str<N>) -> Self {
+        OsString::from(v.as_str())
+    }
+}
+
+impl<const N: usize> From<Str<N>> for PathBuf {
+    #[inline]
+    fn from(v: Str<N>) -> Self {
+        PathBuf::from(v.as_str())
+    }
+}
+
+impl<const N: usize> PartialEq<OsStr> for Str<N> {
+    #[inline]
+    fn eq(&self, other: &OsStr) -> bool {
+        OsStr::new(self.as_str()) == other
+    }
+}
+
+impl<const N: usize> PartialEq<Str<N>> for OsStr {
+    #[inline]
+    fn eq(&self, other: &Str<N>) -> bool {
+        self == OsStr::new(other.as_str())
+    }
+}
@@ -0,0 +1,430 @@
+//! Compact storage of [`Str<N>`] values made of a restricted alphabet.
+//!
+//! A [`Packed<A, N, B>`] stores each character as its index in the alphabet `A`, in
+//! [`A::BITS`](PackAlphabet::BITS) bits instead of 8, all in `B` bytes, the fewest that hold
+//! `N * A::BITS` bits. The alphabets are sorted and characters are packed most significant
+//! first, so packed values sort exactly like the strings they hold.
+//!
+//! # Examples
+//!
+//! ```
+//! use str_array::{packed::{Packed, UpperAlpha}, Str};
+//!
+//! let a = Packed::<UpperAlpha, 5, 4>::pack(&Str::try_new("BRK.A").unwrap()).unwrap();
+//! let b = Packed::<UpperAlpha, 5, 4>::pack(&Str::try_new("BRK.B").unwrap()).unwrap();
+//!
+//! assert_eq!(Packed::<UpperAlpha, 5, 4>::BITS, 25);
+//! assert!(a < b);
+//! assert_eq!(a.unpack(), "BRK.A");
+//! assert_eq!(b.to_string(), "BRK.B");
+//! ```
+
+use crate::Str;
+use core::{cmp::Ordering, fmt, hash, marker::PhantomData};
+
+/// The error returned when a string has a character outside of the alphabet it is packed with.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct NotInAlphabet {
+    index: usize,
+}
+
+impl NotInAlphabet {
+    /// Returns the index of the first byte that is not in the alphabet.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for NotInAlphabet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Expected a character of the alphabet, found another byte at {}",
+            self.index
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NotInAlphabet {}
+
+/// A set of ASCII characters that a [`Packed`] string is restricted to.
+///
+/// Only [`CHARS`](PackAlphabet::CHARS) has to be provided. It must be non-empty, ASCII and
+/// sorted in ascending order without duplicates, which is checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use str_array::{packed::{PackAlphabet, Packed}, Str};
+///
+/// struct Digits;
+///
+/// impl PackAlphabet for Digits {
+///     const CHARS: &'static [u8] = b"0123456789";
+/// }
+///
+/// assert_eq!(Digits::BITS, 4);
+///
+/// let pin = Packed::<Digits, 4, 2>::pack(&Str::try_new("0042").unwrap()).unwrap();
+/// assert_eq!(pin.to_bytes(), [0x00, 0x42]);
+/// ```
+///
+/// An invalid alphabet fails to build with any method of [`Packed`], not only
+/// [`pack`](Packed::pack):
+///
+/// ```compile_fail
+/// use str_array::packed::{PackAlphabet, Packed};
+///
+/// struct Unsorted;
+///
+/// impl PackAlphabet for Unsorted {
+///     const CHARS: &'static [u8] = b"BA";
+/// }
+///
+/// let _ = Packed::<Unsorted, 4, 1>::from_bits(0);
+/// ```
+pub trait PackAlphabet {
+    /// The characters of the alphabet, in ascending order.
+    const CHARS: &'static [u8];
+
+    /// The number of bits needed to store a character of the alphabet.
+    const BITS: u32 = bits_for(Self::CHARS.len());
+
+    #[doc(hidden)]
+    const INDEX: [u8; 256] = index_table(Self::CHARS);
+}
+
+const fn bits_for(len: usize) -> u32 {
+    assert!(len > 0, "PackAlphabet::CHARS is empty");
+    usize::BITS - (len - 1).leading_zeros()
+}
+
+const fn index_table(chars: &[u8]) -> [u8; 256] {
+    let mut table = [u8::MAX; 256];
+    let mut i = 0;
+    while i < chars.len() {
+        assert!(chars[i].is_ascii(), "PackAlphabet::CHARS must be ASCII");
+        assert!(
+            i == 0 || chars[i - 1] < chars[i],
+            "PackAlphabet::CHARS must be sorted without duplicates"
+        );
+        table[chars[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// 5 bits per character: `A` to `Z`, space, `&`, `-`, `.`, `/` and `_`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UpperAlpha;
+
+impl PackAlphabet for UpperAlpha {
+    const CHARS: &'static [u8] = b" &-./ABCDEFGHIJKLMNOPQRSTUVWXYZ_";
+}
+
+/// 6 bits per character: the URL-safe base64 alphabet, `A-Z`, `a-z`, `0-9`, `-` and `_`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Base64Url;
+
+impl PackAlphabet for Base64Url {
+    const CHARS: &'static [u8] =
+        b"-0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz";
+}
+
+/// 7 bits per character: every ASCII character.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ascii7;
+
+impl PackAlphabet for Ascii7 {
+    const CHARS: &'static [u8] = &{
+        let mut chars = [0u8; 128];
+        let mut i = 0;
+        while i < chars.len() {
+            chars[i] = i as u8;
+            i += 1;
+        }
+        chars
+    };
+}
+
+/// A [`Str<N>`] restricted to the alphabet `A`, packed into `B` bytes.
+///
+/// `B` must be the number of bytes that hold [`BITS`](Self::BITS), `N * A::BITS` rounded up
+/// to a multiple of 8, and `BITS` must be at most 64. Both are checked at compile time: that is
+/// up to 12 characters of [`UpperAlpha`], 10 of [`Base64Url`] or 9 of [`Ascii7`]. The bytes
+/// are the packed integer in big-endian order, so packed values compare, order and hash as
+/// bytes, with the same order as the strings.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{packed::{Packed, UpperAlpha}, Str};
+/// // 3 characters of 5 bits take 2 bytes instead of 3.
+/// assert_eq!(size_of::<Packed<UpperAlpha, 3, 2>>(), 2);
+/// assert_eq!(size_of::<Str<3>>(), 3);
+///
+/// assert_eq!(size_of::<Packed<UpperAlpha, 12, 8>>(), 8);
+/// assert_eq!(size_of::<Str<12>>(), 12);
+/// ```
+///
+/// Any other `B` is rejected at compile time:
+///
+/// ```compile_fail
+/// # use str_array::{packed::{Packed, UpperAlpha}, Str};
+/// let s = Packed::<UpperAlpha, 3, 3>::pack(&Str::try_new("IBM").unwrap());
+/// ```
+pub struct Packed<A, const N: usize, const B: usize> {
+    bytes: [u8; B],
+    alphabet: PhantomData<A>,
+}
+
+impl<A: PackAlphabet, const N: usize, const B: usize> Packed<A, N, B> {
+    /// The number of bits used by a packed value, the rest of its bytes is always zero.
+    pub const BITS: u32 = N as u32 * A::BITS;
+
+    const MASK: u64 = (1 << A::BITS) - 1;
+
+    /// Checks the alphabet and the size, so that invalid `Packed` types fail to build whichever
+    /// method they use.
+    const CHECK: () = {
+        let bits = N as u64 * A::BITS as u64;
+        assert!(bits <= 64, "Packed<A, N, B> does not fit in a u64");
+        assert!(
+            B as u64 == bits.div_ceil(8),
+            "Packed<A, N, B> needs B to be the number of bytes of N * A::BITS bits"
+        );
+        // Evaluating the table runs the checks of `PackAlphabet::CHARS`.
+        let _ = A::INDEX;
+    };
+
+    /// Packs a [`Str<N>`] whose characters are all in the alphabet.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NotInAlphabet`] with the index of the first byte that is not in the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{packed::{Base64Url, Packed}, Str};
+    /// let id = Packed::<Base64Url, 8, 6>::pack(&Str::try_new("x-9_Az0q").unwrap()).unwrap();
+    /// assert_eq!(id.unpack(), "x-9_Az0q");
+    ///
+    /// let err = Packed::<Base64Url, 8, 6>::pack(&Str::try_new("x-9_Az0?").unwrap()).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 7);
+    /// ```
+    ///
+    /// Strings that do not fit in a `u64` are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # use str_array::{packed::{Base64Url, Packed}, Str};
+    /// let id = Packed::<Base64Url, 11, 9>::pack(&Str::try_new("x-9_Az0q123").unwrap());
+    /// ```
+    #[inline]
+    pub const fn pack(s: &Str<N>) -> Result<Self, NotInAlphabet> {
+        let () = Self::CHECK;
+        let bytes = s.as_bytes();
+        let mut bits = 0u64;
+        let mut i = 0;
+        while i < N {
+            let index = A::INDEX[bytes[i] as usize];
+            if index == u8::MAX {
+                return Err(NotInAlphabet { index: i });
+            }
+            bits = (bits << A::BITS) | index as u64;
+            i += 1;
+        }
+        Ok(Self::from_bits_unchecked(bits))
+    }
+
+    /// Unpacks the string.
+    #[inline]
+    #[must_use]
+    pub const fn unpack(&self) -> Str<N> {
+        let () = Self::CHECK;
+        let mut v = [0u8; N];
+        let mut bits = self.to_bits();
+        let mut i = N;
+        while i > 0 {
+            i -= 1;
+            v[i] = A::CHARS[(bits & Self::MASK) as usize];
+            bits >>= A::BITS;
+        }
+        // Safety: the alphabet is ASCII.
+        unsafe { Str::from_utf8_unchecked(v) }
+    }
+
+    /// Returns the packed bytes.
+    #[inline]
+    #[must_use]
+    pub const fn to_bytes(&self) -> [u8; B] {
+        self.bytes
+    }
+
+    /// Creates a packed value from bytes returned by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `None` if `bytes` is not a valid packed value, like [`from_bits`](Self::from_bits).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{packed::{Packed, UpperAlpha}, Str};
+    /// let s = Packed::<UpperAlpha, 6, 4>::pack(&Str::try_new("NVDA  ").unwrap()).unwrap();
+    /// assert_eq!(Packed::<UpperAlpha, 6, 4>::from_bytes(s.to_bytes()), Some(s));
+    /// assert_eq!(Packed::<UpperAlpha, 6, 4>::from_bytes([0xFF; 4]), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bytes(bytes: [u8; B]) -> Option<Self> {
+        let () = Self::CHECK;
+        let mut bits = 0u64;
+        let mut i = 0;
+        while i < B {
+            bits = (bits << 8) | bytes[i] as u64;
+            i += 1;
+        }
+        Self::from_bits(bits)
+    }
+
+    /// Returns the packed integer, which only uses its lowest [`BITS`](Self::BITS) bits.
+    #[inline]
+    #[must_use]
+    pub const fn to_bits(&self) -> u64 {
+        let mut bits = 0u64;
+        let mut i = 0;
+        while i < B {
+            bits = (bits << 8) | self.bytes[i] as u64;
+            i += 1;
+        }
+        bits
+    }
+
+    /// Creates a packed value from an integer returned by [`to_bits`](Self::to_bits).
+    ///
+    /// Returns `None` if `bits` is not a valid packed value: if it uses more than
+    /// [`BITS`](Self::BITS) bits, or if a character index is outside of the alphabet.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{packed::{Packed, UpperAlpha}, Str};
+    /// let s = Packed::<UpperAlpha, 6, 4>::pack(&Str::try_new("NVDA  ").unwrap()).unwrap();
+    /// assert_eq!(Packed::<UpperAlpha, 6, 4>::from_bits(s.to_bits()), Some(s));
+    /// assert_eq!(Packed::<UpperAlpha, 6, 4>::from_bits(u64::MAX), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_bits(bits: u64) -> Option<Self> {
+        let () = Self::CHECK;
+        if Self::BITS < 64 && bits >> Self::BITS != 0 {
+            return None;
+        }
+        let mut rest = bits;
+        let mut i = 0;
+        while i < N {
+            if (rest & Self::MASK) as usize >= A::CHARS.len() {
+                return None;
+            }
+            rest >>= A::BITS;
+            i += 1;
+        }
+        Some(Self::from_bits_unchecked(bits))
+    }
+
+    /// Stores the lowest `B` bytes of `bits`, most significant first.
+    #[inline]
+    const fn from_bits_unchecked(bits: u64) -> Self {
+        let be = bits.to_be_bytes();
+        let mut bytes = [0u8; B];
+        let mut i = 0;
+        while i < B {
+            bytes[i] = be[be.len() - B + i];
+            i += 1;
+        }
+        Self {
+            bytes,
+            alphabet: PhantomData,
+        }
+    }
+}
+
+impl<A: PackAlphabet, const N: usize, const B: usize> TryFrom<Str<N>> for Packed<A, N, B> {
+    type Error = NotInAlphabet;
+    /// Try to pack a [`Str<N>`].
+    ///
+    /// The result will fail if a character is not in the alphabet.
+    #[inline]
+    fn try_from(v: Str<N>) -> Result<Self, Self::Error> {
+        Self::pack(&v)
+    }
+}
+
+impl<A: PackAlphabet, const N: usize, const B: usize> From<Packed<A, N, B>> for Str<N> {
+    #[inline]
+    fn from(v: Packed<A, N, B>) -> Self {
+        v.unpack()
+    }
+}
+
+impl<A, const N: usize, const B: usize> Copy for Packed<A, N, B> {}
+
+impl<A, const N: usize, const B: usize> Clone for Packed<A, N, B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A, const N: usize, const B: usize> PartialEq for Packed<A, N, B> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+impl<A, const N: usize, const B: usize> Eq for Packed<A, N, B> {}
+
+impl<A, const N: usize, const B: usize> PartialOrd for Packed<A, N, B> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<A, const N: usize, const B: usize> Ord for Packed<A, N, B> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Big-endian bytes of the same length order like the integer.
+        self.bytes.cmp(&other.bytes)
+    }
+}
+
+impl<A, const N: usize, const B: usize> hash::Hash for Packed<A, N, B> {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.bytes.hash(hasher)
+    }
+}
+
+impl<A: PackAlphabet, const N: usize, const B: usize> fmt::Display for Packed<A, N, B> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.unpack(), f)
+    }
+}
+
+impl<A: PackAlphabet, const N: usize, const B: usize> fmt::Debug for Packed<A, N, B> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.unpack(), f)
+    }
+}
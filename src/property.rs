@@ -0,0 +1,144 @@
+//! Integrations with property testing and fuzzing crates.
+//!
+//! All of them build a `Str<N>` from a sequence of `(width, seed)` choices: each choice is
+//! clamped to the bytes left and turned into a character of exactly that UTF-8 width, so any
+//! sequence, including the shrunk ones, fills the `N` bytes exactly. Missing choices are filled
+//! with `'a'`, which is also what a seed of `0` maps to for ASCII.
+
+use crate::Str;
+
+/// Maps `seed` to a character of exactly `width` UTF-8 bytes.
+fn char_with_width(width: usize, seed: u32) -> char {
+    let (start, len) = match width {
+        1 => return char::from(((u32::from(b'a') + seed % 0x80) % 0x80) as u8),
+        2 => (0x80, 0x800 - 0x80),
+        // Skip the surrogates, which are not valid `char`s.
+        3 => (0x800, 0x1_0000 - 0x800 - 0x800),
+        _ => (0x1_0000, 0x11_0000 - 0x1_0000),
+    };
+    let mut c = start + seed % len;
+    if width == 3 && c >= 0xD800 {
+        c += 0x800;
+    }
+    char::from_u32(c).unwrap_or('a')
+}
+
+fn from_choices<const N: usize>(choices: impl IntoIterator<Item = (usize, u32)>) -> Str<N> {
+    let mut out = [b'a'; N];
+    let mut i = 0;
+    for (width, seed) in choices {
+        if i == N {
+            break;
+        }
+        let width = width.clamp(1, (N - i).min(4));
+        let ch = char_with_width(width, seed);
+        ch.encode_utf8(&mut out[i..i + width]);
+        i += width;
+    }
+    // Safety: `out` holds whole characters, followed by ASCII `'a'`s.
+    unsafe { Str::from_utf8_unchecked(out) }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, const N: usize> arbitrary::Arbitrary<'a> for Str<N> {
+    /// Generates a `Str<N>` mixing characters of every UTF-8 width.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use arbitrary::{Arbitrary, Unstructured};
+    /// use str_array::Str;
+    ///
+    /// let data = [3, 0x12, 0x34, 0x56, 0x78, 0, 1, 2, 3, 4];
+    /// let s = Str::<8>::arbitrary(&mut Unstructured::new(&data)).unwrap();
+    /// assert_eq!(s.len(), 8);
+    /// ```
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let mut choices = [(1, 0); N];
+        for choice in choices.iter_mut() {
+            if u.is_empty() {
+                break;
+            }
+            *choice = (u.int_in_range(1..=4)?, u.arbitrary()?);
+        }
+        Ok(from_choices(choices))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(N * 5))
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl<const N: usize> proptest::arbitrary::Arbitrary for Str<N> {
+    type Parameters = ();
+    type Strategy = proptest::strategy::Map<
+        proptest::collection::VecStrategy<(
+            core::ops::RangeInclusive<usize>,
+            proptest::num::u32::Any,
+        )>,
+        fn(Vec<(usize, u32)>) -> Self,
+    >;
+
+    /// Generates a `Str<N>` mixing characters of every UTF-8 width, shrinking towards ASCII.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use proptest::prelude::*;
+    /// use str_array::Str;
+    ///
+    /// proptest!(|(s in any::<Str<8>>())| {
+    ///     prop_assert_eq!(s.as_str().len(), 8);
+    /// });
+    /// ```
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::{arbitrary::any, strategy::Strategy};
+        proptest::collection::vec((1..=4, any::<u32>()), 0..=N).prop_map(from_choices)
+    }
+}
+
+#[cfg(feature = "quickcheck")]
+impl<const N: usize> quickcheck::Arbitrary for Str<N> {
+    /// Generates a `Str<N>` mixing characters of every UTF-8 width.
+    ///
+    /// Shrinking replaces one character at a time with as many `'a'`s as its width.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use quickcheck::QuickCheck;
+    /// use str_array::Str;
+    ///
+    /// fn exact_length(s: Str<8>) -> bool {
+    ///     s.as_str().len() == 8
+    /// }
+    ///
+    /// QuickCheck::new().quickcheck(exact_length as fn(Str<8>) -> bool);
+    /// ```
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        let mut choices = [(1, 0); N];
+        for choice in choices.iter_mut() {
+            let width = *g.choose(&[1, 2, 3, 4]).unwrap_or(&1);
+            *choice = (width, <u32 as quickcheck::Arbitrary>::arbitrary(g));
+        }
+        from_choices(choices)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let s = *self;
+        Box::new((0..N).filter_map(move |i| {
+            let ch = s.get(i..)?.chars().next().filter(|&ch| ch != 'a')?;
+            let mut out = s;
+            out.v[i..i + ch.len_utf8()].fill(b'a');
+            Some(out)
+        }))
+    }
+}
@@ -0,0 +1,228 @@
+//! Random generation of [`Str<N>`] values, enabled by the `rand` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use rand::{rngs::SmallRng, SeedableRng};
+//! use str_array::{random::Alphabet, Str};
+//!
+//! let mut rng = SmallRng::seed_from_u64(42);
+//! let coupon: Str<8> = Str::random_from_alphabet(&mut rng, &Alphabet::UNAMBIGUOUS);
+//! assert!(coupon.chars().all(|c| Alphabet::UNAMBIGUOUS.as_str().contains(c)));
+//! ```
+
+use crate::Str;
+use core::fmt;
+use rand::{
+    distributions::{Alphanumeric, Distribution, Uniform},
+    Rng,
+};
+
+/// The error returned by [`Alphabet::new`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InvalidAlphabet {
+    /// The alphabet has no characters.
+    Empty,
+    /// The alphabet's characters do not all have the same UTF-8 width.
+    MixedWidths,
+}
+
+impl fmt::Display for InvalidAlphabet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidAlphabet::Empty => f.write_str("Alphabet is empty"),
+            InvalidAlphabet::MixedWidths => {
+                f.write_str("Alphabet characters have different UTF-8 widths")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidAlphabet {}
+
+/// A set of characters to sample from, all of the same UTF-8 width.
+///
+/// Every character is picked with the same probability, so a character listed twice is twice as
+/// likely to appear.
+///
+/// `Alphabet` implements [`Distribution<Str<N>>`], so it can be used with [`Rng::sample`].
+/// Sampling panics if `N` is not a multiple of the alphabet's [`char_width`].
+///
+/// [`char_width`]: Alphabet::char_width
+///
+/// # Examples
+///
+/// ```
+/// use rand::{rngs::SmallRng, Rng, SeedableRng};
+/// use str_array::{random::Alphabet, Str};
+///
+/// let mut rng = SmallRng::seed_from_u64(7);
+/// let suits = Alphabet::new("♠♥♦♣").unwrap();
+/// assert_eq!(suits.char_width(), 3);
+///
+/// let hand: Str<15> = rng.sample(&suits);
+/// assert_eq!(hand.chars().count(), 5);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Alphabet<'a> {
+    chars: &'a str,
+    width: usize,
+}
+
+impl<'a> Alphabet<'a> {
+    /// ASCII digits and letters, `0-9A-Za-z`.
+    pub const ALPHANUMERIC: Alphabet<'static> =
+        Alphabet::new_unwrap("0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+    /// Lowercase hexadecimal digits, `0-9a-f`.
+    pub const HEX: Alphabet<'static> = Alphabet::new_unwrap("0123456789abcdef");
+    /// Crockford's base32, digits and uppercase letters without `I`, `L`, `O` and `U`.
+    pub const CROCKFORD_BASE32: Alphabet<'static> =
+        Alphabet::new_unwrap("0123456789ABCDEFGHJKMNPQRSTVWXYZ");
+    /// Digits and letters that cannot be mistaken for one another when read by a human,
+    /// i.e. without `0`, `O`, `o`, `1`, `I` and `l`.
+    pub const UNAMBIGUOUS: Alphabet<'static> =
+        Alphabet::new_unwrap("23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnpqrstuvwxyz");
+
+    /// Creates an alphabet from the characters of `chars`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAlphabet`] if `chars` is empty, or if its characters do not all have the
+    /// same length in UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::random::{Alphabet, InvalidAlphabet};
+    /// assert!(Alphabet::new("αβγ").is_ok());
+    /// assert_eq!(Alphabet::new(""), Err(InvalidAlphabet::Empty));
+    /// assert_eq!(Alphabet::new("aβ"), Err(InvalidAlphabet::MixedWidths));
+    /// ```
+    pub const fn new(chars: &'a str) -> Result<Self, InvalidAlphabet> {
+        let bytes = chars.as_bytes();
+        if bytes.is_empty() {
+            return Err(InvalidAlphabet::Empty);
+        }
+        let width = utf8_char_width(bytes[0]);
+        if !bytes.len().is_multiple_of(width) {
+            return Err(InvalidAlphabet::MixedWidths);
+        }
+        // Every character must start exactly at a multiple of `width`.
+        let mut i = 0;
+        while i < bytes.len() {
+            let is_start = (bytes[i] as i8) >= -0x40;
+            if is_start != i.is_multiple_of(width) {
+                return Err(InvalidAlphabet::MixedWidths);
+            }
+            i += 1;
+        }
+        Ok(Self { chars, width })
+    }
+
+    const fn new_unwrap(chars: &'a str) -> Self {
+        match Self::new(chars) {
+            Ok(a) => a,
+            Err(_) => panic!("Invalid alphabet"),
+        }
+    }
+
+    /// Returns the characters of this alphabet.
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.chars
+    }
+
+    /// Returns the UTF-8 width in bytes shared by every character of this alphabet.
+    #[inline]
+    #[must_use]
+    pub const fn char_width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of characters in this alphabet.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.chars.len() / self.width
+    }
+
+    /// Always returns `false`, as alphabets cannot be empty.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+#[inline]
+const fn utf8_char_width(first: u8) -> usize {
+    match first {
+        0x00..=0x7F => 1,
+        0xC0..=0xDF => 2,
+        0xE0..=0xEF => 3,
+        _ => 4,
+    }
+}
+
+impl<const N: usize> Distribution<Str<N>> for Alphabet<'_> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Str<N> {
+        assert!(
+            N.is_multiple_of(self.width),
+            "Str<{}> cannot be filled with characters of {} bytes",
+            N,
+            self.width
+        );
+        let index = Uniform::new(0, self.len());
+        let bytes = self.chars.as_bytes();
+        let mut out = [0u8; N];
+        for chunk in out.chunks_exact_mut(self.width) {
+            let i = index.sample(rng) * self.width;
+            chunk.copy_from_slice(&bytes[i..i + self.width]);
+        }
+        // Safety: `out` is a sequence of whole characters copied from `self.chars`.
+        unsafe { Str::from_utf8_unchecked(out) }
+    }
+}
+
+impl<const N: usize> Distribution<Str<N>> for Alphanumeric {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Str<N> {
+        let mut out = [0u8; N];
+        for b in out.iter_mut() {
+            *b = Alphanumeric.sample(rng);
+        }
+        // Safety: `Alphanumeric` only yields ASCII bytes.
+        unsafe { Str::from_utf8_unchecked(out) }
+    }
+}
+
+impl<const N: usize> Str<N> {
+    /// Generates a random `Str` made of characters of `alphabet`, each picked uniformly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is not a multiple of `alphabet`'s [`char_width`](Alphabet::char_width).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use rand::{rngs::SmallRng, SeedableRng};
+    /// use str_array::{random::Alphabet, Str};
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(1);
+    /// let id: Str<16> = Str::random_from_alphabet(&mut rng, &Alphabet::HEX);
+    /// assert!(id.bytes().all(|b| b.is_ascii_hexdigit()));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn random_from_alphabet<R: Rng + ?Sized>(rng: &mut R, alphabet: &Alphabet<'_>) -> Self {
+        alphabet.sample(rng)
+    }
+}
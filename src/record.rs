@@ -0,0 +1,249 @@
+//! Runtime support for `#[derive(FixedRecord)]`.
+//!
+//! A fixed-layout record is a struct whose fields are laid out back to back in a byte array of
+//! a width known at compile time. Text fields are any type implementing [`FixedField`] (e.g.
+//! [`Str<N>`]), numeric fields are integers rendered as ASCII digits through [`NumericField`].
+
+use crate::{AsciiStr, Str};
+use core::{fmt, num::IntErrorKind, str};
+
+/// How a numeric field is aligned inside its width.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Align {
+    /// Digits are placed at the start of the field, padding follows.
+    Left,
+    /// Digits are placed at the end of the field, padding precedes.
+    Right,
+}
+
+/// The reason a single field failed to parse or serialize.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FieldError {
+    /// The field's bytes are not valid UTF-8.
+    Utf8(str::Utf8Error),
+    /// An ASCII field contains a byte above `127`.
+    NotAscii,
+    /// A numeric field contains something other than digits, a sign, or padding.
+    InvalidDigit,
+    /// A numeric value does not fit in the field's type or width.
+    Overflow,
+}
+
+impl fmt::Display for FieldError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FieldError::Utf8(e) => fmt::Display::fmt(e, f),
+            FieldError::NotAscii => f.write_str("non-ASCII byte in ASCII field"),
+            FieldError::InvalidDigit => f.write_str("invalid digit in numeric field"),
+            FieldError::Overflow => f.write_str("number does not fit in the field"),
+        }
+    }
+}
+
+/// An error locating the failing field of a fixed-layout record.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecordError {
+    field: &'static str,
+    offset: usize,
+    error: FieldError,
+}
+
+impl RecordError {
+    #[doc(hidden)]
+    #[inline]
+    pub const fn new(field: &'static str, offset: usize, error: FieldError) -> Self {
+        Self {
+            field,
+            offset,
+            error,
+        }
+    }
+
+    /// The name of the field that failed.
+    #[inline]
+    #[must_use]
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+
+    /// The byte offset of the failing field inside the record.
+    #[inline]
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// What went wrong with the field.
+    #[inline]
+    #[must_use]
+    pub const fn error(&self) -> FieldError {
+        self.error
+    }
+}
+
+impl fmt::Display for RecordError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid field `{}` at offset {}: {}",
+            self.field, self.offset, self.error
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldError {}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecordError {}
+
+/// A value with a fixed serialized width that can be embedded in a record as is.
+pub trait FixedField: Sized {
+    /// The number of bytes this field occupies.
+    const WIDTH: usize;
+
+    /// Parses the field from exactly [`WIDTH`](FixedField::WIDTH) bytes.
+    fn read_field(bytes: &[u8]) -> Result<Self, FieldError>;
+
+    /// Writes the field into exactly [`WIDTH`](FixedField::WIDTH) bytes.
+    fn write_field(&self, out: &mut [u8]);
+}
+
+impl<const N: usize> FixedField for Str<N> {
+    const WIDTH: usize = N;
+
+    #[inline]
+    fn read_field(bytes: &[u8]) -> Result<Self, FieldError> {
+        let mut v = [0u8; N];
+        v.copy_from_slice(bytes);
+        Str::from_utf8(v).map_err(FieldError::Utf8)
+    }
+
+    #[inline]
+    fn write_field(&self, out: &mut [u8]) {
+        out.copy_from_slice(self.as_bytes());
+    }
+}
+
+impl<const N: usize> FixedField for AsciiStr<N> {
+    const WIDTH: usize = N;
+
+    #[inline]
+    fn read_field(bytes: &[u8]) -> Result<Self, FieldError> {
+        let mut v = [0u8; N];
+        v.copy_from_slice(bytes);
+        AsciiStr::from_ascii(v).map_err(|_| FieldError::NotAscii)
+    }
+
+    #[inline]
+    fn write_field(&self, out: &mut [u8]) {
+        out.copy_from_slice(self.as_bytes());
+    }
+}
+
+/// An integer that can be stored as ASCII digits in a fixed-width field.
+///
+/// Negative numbers are prefixed with `-`. When the field is right aligned and padded with
+/// `'0'` the sign comes first (`-0042`), otherwise the padding surrounds the whole number
+/// (`  -42`).
+pub trait NumericField: Sized {
+    /// Parses the field, ignoring `pad` bytes on the padding side.
+    fn read_numeric(bytes: &[u8], pad: u8, align: Align) -> Result<Self, FieldError>;
+
+    /// Writes the number into `out`, filling the rest with `pad`.
+    ///
+    /// Returns [`FieldError::Overflow`] if the number has more digits than `out` can hold.
+    fn write_numeric(&self, out: &mut [u8], pad: u8, align: Align) -> Result<(), FieldError>;
+}
+
+#[inline]
+fn trim_padding(bytes: &[u8], pad: u8, align: Align) -> &[u8] {
+    match align {
+        Align::Left => {
+            let end = bytes.iter().rposition(|&b| b != pad).map_or(0, |i| i + 1);
+            &bytes[..end]
+        }
+        Align::Right => {
+            let start = bytes.iter().position(|&b| b != pad).unwrap_or(bytes.len());
+            &bytes[start..]
+        }
+    }
+}
+
+fn write_digits(
+    out: &mut [u8],
+    negative: bool,
+    mut magnitude: u128,
+    pad: u8,
+    align: Align,
+) -> Result<(), FieldError> {
+    let mut buf = [0u8; 40];
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (magnitude % 10) as u8;
+        magnitude /= 10;
+        if magnitude == 0 {
+            break;
+        }
+    }
+    let digits = &buf[start..];
+    let len = digits.len() + usize::from(negative);
+    if len > out.len() {
+        return Err(FieldError::Overflow);
+    }
+    out.fill(pad);
+    let at = match align {
+        Align::Left => 0,
+        Align::Right => out.len() - len,
+    };
+    if negative {
+        // Keep `-0042` readable as a number when zero padding is used.
+        let sign_at = if pad == b'0' && align == Align::Right {
+            0
+        } else {
+            at
+        };
+        out[sign_at] = b'-';
+    }
+    out[at + usize::from(negative)..at + len].copy_from_slice(digits);
+    Ok(())
+}
+
+macro_rules! impl_numeric_field {
+    ($($t: ty => |$v: ident| $magnitude: expr),*) => {$(
+        impl NumericField for $t {
+            fn read_numeric(bytes: &[u8], pad: u8, align: Align) -> Result<Self, FieldError> {
+                let digits = trim_padding(bytes, pad, align);
+                if digits.is_empty() && pad == b'0' {
+                    return Ok(0);
+                }
+                // `parse` accepts a leading `+`, which `write_numeric` never writes.
+                if digits.first() == Some(&b'+') {
+                    return Err(FieldError::InvalidDigit);
+                }
+                let digits = str::from_utf8(digits).map_err(|_| FieldError::InvalidDigit)?;
+                digits.parse::<$t>().map_err(|e| match e.kind() {
+                    IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => FieldError::Overflow,
+                    _ => FieldError::InvalidDigit,
+                })
+            }
+
+            #[inline]
+            fn write_numeric(&self, out: &mut [u8], pad: u8, align: Align) -> Result<(), FieldError> {
+                let $v = *self;
+                #[allow(unused_comparisons)]
+                let negative = $v < 0;
+                write_digits(out, negative, $magnitude as u128, pad, align)
+            }
+        }
+    )*};
+}
+
+impl_numeric_field! {
+    u8 => |v| v, u16 => |v| v, u32 => |v| v, u64 => |v| v, u128 => |v| v, usize => |v| v,
+    i8 => |v| v.unsigned_abs(), i16 => |v| v.unsigned_abs(), i32 => |v| v.unsigned_abs(),
+    i64 => |v| v.unsigned_abs(), i128 => |v| v.unsigned_abs(), isize => |v| v.unsigned_abs()
+}
@@ -0,0 +1,179 @@
+use crate::{InvalidLength, Str};
+use core::{fmt, hint, ptr, str, sync::atomic};
+
+/// A fixed-width secret, such as an API token or a PIN.
+///
+/// Unlike [`Str`], a `SecretStr` is not [`Copy`], compares in constant time, wipes its bytes
+/// when dropped and never prints its contents. The value can only be read through
+/// [`expose`](SecretStr::expose).
+///
+/// Note that moving a `SecretStr` is a plain memory copy, so the bytes may still linger in
+/// previous stack locations; keep secrets in place (e.g. behind a reference or a `Box`) when
+/// that matters.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::SecretStr;
+/// let pin: SecretStr<4> = SecretStr::try_new("1234").unwrap();
+///
+/// assert_eq!(format!("{:?}", pin), "SecretStr<4>(***)");
+/// assert_eq!(pin.to_string(), "***");
+/// assert_eq!(pin.expose(), "1234");
+/// assert!(pin == *"1234");
+/// ```
+pub struct SecretStr<const N: usize> {
+    inner: Str<N>,
+}
+
+impl<const N: usize> SecretStr<N> {
+    /// Wraps a [`Str`] as a secret.
+    #[inline]
+    #[must_use]
+    pub const fn new(inner: Str<N>) -> Self {
+        Self { inner }
+    }
+
+    /// Copies a `&str` into a new secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string's length is not exactly `N`.
+    #[inline]
+    pub const fn try_new(s: &str) -> Result<Self, InvalidLength> {
+        match Str::try_new(s) {
+            Ok(inner) => Ok(Self::new(inner)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Converts an array of bytes into a secret, checking that it is valid UTF-8.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Err`] if the bytes are not UTF-8, see [`Str::from_utf8`].
+    #[inline]
+    pub const fn from_utf8(v: [u8; N]) -> Result<Self, str::Utf8Error> {
+        match Str::from_utf8(v) {
+            Ok(inner) => Ok(Self::new(inner)),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Gives access to the secret value.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::SecretStr;
+    /// let token: SecretStr<6> = SecretStr::try_new("s3cr3t").unwrap();
+    /// assert_eq!(token.expose().as_str(), "s3cr3t");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn expose(&self) -> &Str<N> {
+        &self.inner
+    }
+
+    /// Compares the secret with `other` in time that depends only on their lengths.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::SecretStr;
+    /// let pin: SecretStr<4> = SecretStr::try_new("1234").unwrap();
+    /// assert!(pin.ct_eq(b"1234"));
+    /// assert!(!pin.ct_eq(b"1243"));
+    /// assert!(!pin.ct_eq(b"123"));
+    /// ```
+    #[must_use]
+    pub fn ct_eq(&self, other: &[u8]) -> bool {
+        // The length is part of the type, so it is not secret.
+        if other.len() != N {
+            return false;
+        }
+        let diff = self
+            .inner
+            .v
+            .iter()
+            .zip(other)
+            .fold(0u8, |acc, (a, b)| hint::black_box(acc | (a ^ b)));
+        hint::black_box(diff) == 0
+    }
+}
+
+impl<const N: usize> Drop for SecretStr<N> {
+    #[inline]
+    fn drop(&mut self) {
+        for b in self.inner.v.iter_mut() {
+            // Safety: `b` is a valid, aligned `&mut u8`. NUL bytes keep the `Str` valid UTF-8.
+            unsafe { ptr::write_volatile(b, 0) };
+        }
+        atomic::compiler_fence(atomic::Ordering::SeqCst);
+    }
+}
+
+impl<const N: usize> Clone for SecretStr<N> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.inner)
+    }
+}
+
+impl<const N: usize> From<Str<N>> for SecretStr<N> {
+    #[inline]
+    fn from(v: Str<N>) -> Self {
+        Self::new(v)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for SecretStr<N> {
+    type Error = InvalidLength;
+    /// Try to convert a `&str` into a [`SecretStr<N>`].
+    ///
+    /// The result will fail if the string's length is not exactly `N`.
+    #[inline]
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const N: usize> PartialEq for SecretStr<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(&other.inner.v)
+    }
+}
+impl<const N: usize> Eq for SecretStr<N> {}
+
+impl<const N: usize> PartialEq<str> for SecretStr<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.ct_eq(other.as_bytes())
+    }
+}
+
+impl<const N: usize> PartialEq<Str<N>> for SecretStr<N> {
+    #[inline]
+    fn eq(&self, other: &Str<N>) -> bool {
+        self.ct_eq(other.as_bytes())
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretStr<{}>(***)", N)
+    }
+}
+
+impl<const N: usize> fmt::Display for SecretStr<N> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("***")
+    }
+}
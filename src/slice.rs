@@ -0,0 +1,345 @@
+//! Operations on slices of [`Str<N>`]: viewing a buffer of records as a slice, radix sorting,
+//! and prefix searches on sorted slices.
+//!
+//! # Examples
+//!
+//! ```
+//! use str_array::{slice, Str};
+//!
+//! let mut table = *b"MSFTAAPLAMZNAAPL";
+//! let symbols = Str::<4>::slice_from_bytes_mut(&mut table).unwrap();
+//! slice::sort_radix(symbols);
+//! assert_eq!(symbols, ["AAPL", "AAPL", "AMZN", "MSFT"]);
+//!
+//! assert_eq!(slice::equal_range_by_prefix(symbols, "A"), 0..3);
+//! assert_eq!(slice::longest_common_prefix(&symbols[..3]), "A");
+//! ```
+
+use crate::{run_utf8_validation, Str};
+use core::{fmt, mem, ops::Range, slice, str};
+
+/// The error returned when viewing a byte buffer as a slice of [`Str<N>`] records.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub enum SliceError {
+    /// The buffer's length is not a multiple of `N`, so it ends with a partial record.
+    PartialRecord {
+        /// The number of whole records in the buffer.
+        records: usize,
+        /// The length of the partial record at the end.
+        len: usize,
+    },
+    /// A record is not valid UTF-8.
+    InvalidRecord {
+        /// The index of the first invalid record.
+        index: usize,
+        /// Why the record is not valid UTF-8, relative to the start of the record.
+        error: str::Utf8Error,
+    },
+}
+
+impl SliceError {
+    /// Returns the number of records before the error, which are all valid.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        match *self {
+            SliceError::PartialRecord { records, .. } => records,
+            SliceError::InvalidRecord { index, .. } => index,
+        }
+    }
+}
+
+impl fmt::Display for SliceError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceError::PartialRecord { records, len } => write!(
+                f,
+                "Expected whole records, but got {} and a partial record of {} bytes",
+                records, len
+            ),
+            SliceError::InvalidRecord { index, error } => {
+                write!(f, "Invalid record at index {}: {}", index, error)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SliceError {}
+
+impl<const N: usize> Str<N> {
+    /// Views a buffer of fixed-width records as a slice of `Str`s, without copying.
+    ///
+    /// The whole buffer is validated in a single pass, with a fast path for ASCII, and then
+    /// only the first byte of each record is checked to make sure no character straddles two
+    /// records. This is meant for large tables, such as a memory-mapped file of records.
+    ///
+    /// Using it with `N == 0` fails to build.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::PartialRecord`] if the buffer's length is not a multiple of `N`, and
+    /// [`SliceError::InvalidRecord`] with the index of the first record that is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{slice::SliceError, Str};
+    /// let table = b"AAPLMSFTGOOG";
+    /// let symbols = Str::<4>::slice_from_bytes(table).unwrap();
+    /// assert_eq!(symbols, ["AAPL", "MSFT", "GOOG"]);
+    ///
+    /// // "é" is valid UTF-8, but its two bytes are split between the first and second records.
+    /// let table = "abcé.ef".as_bytes();
+    /// let err = Str::<4>::slice_from_bytes(table).unwrap_err();
+    /// assert!(matches!(err, SliceError::InvalidRecord { index: 0, .. }));
+    ///
+    /// let err = Str::<4>::slice_from_bytes(b"AAPLMSF").unwrap_err();
+    /// assert_eq!(err, SliceError::PartialRecord { records: 1, len: 3 });
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # use str_array::Str;
+    /// Str::<0>::slice_from_bytes(b"");
+    /// ```
+    #[inline]
+    pub const fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], SliceError> {
+        if let Err(e) = validate_records::<N>(bytes) {
+            return Err(e);
+        }
+        // Safety: `Str<N>` is a transparent `[u8; N]`, and we checked that every record is valid
+        // UTF-8.
+        Ok(unsafe { slice::from_raw_parts(bytes.as_ptr().cast::<Self>(), bytes.len() / N) })
+    }
+
+    /// Views a mutable buffer of fixed-width records as a mutable slice of `Str`s, without
+    /// copying.
+    ///
+    /// See [`slice_from_bytes`](Str::slice_from_bytes) for how the buffer is validated.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SliceError::PartialRecord`] if the buffer's length is not a multiple of `N`, and
+    /// [`SliceError::InvalidRecord`] with the index of the first record that is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Str;
+    /// let mut table = *b"aaplmsft";
+    /// for symbol in Str::<4>::slice_from_bytes_mut(&mut table).unwrap() {
+    ///     symbol.make_ascii_uppercase();
+    /// }
+    /// assert_eq!(&table, b"AAPLMSFT");
+    /// ```
+    #[inline]
+    pub const fn slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], SliceError> {
+        if let Err(e) = validate_records::<N>(bytes) {
+            return Err(e);
+        }
+        let len = bytes.len() / N;
+        // Safety: `Str<N>` is a transparent `[u8; N]`, and we checked that every record is valid
+        // UTF-8.
+        Ok(unsafe { slice::from_raw_parts_mut(bytes.as_mut_ptr().cast::<Self>(), len) })
+    }
+}
+
+/// Checks that `bytes` is a sequence of valid UTF-8 records of `N` bytes.
+const fn validate_records<const N: usize>(bytes: &[u8]) -> Result<(), SliceError> {
+    const { assert!(N > 0, "records must not be empty") };
+    let records = bytes.len() / N;
+    let len = bytes.len() % N;
+    if len != 0 {
+        return Err(SliceError::PartialRecord { records, len });
+    }
+    if bytes.is_ascii() {
+        return Ok(());
+    }
+    // A record is valid if its bytes are valid UTF-8 as part of the whole buffer, and both of
+    // its ends are character boundaries.
+    let valid_up_to = match run_utf8_validation(bytes) {
+        Ok(()) => bytes.len(),
+        Err(e) => e.valid_up_to(),
+    };
+    let mut index = 1;
+    while index * N < valid_up_to && index < records {
+        if is_continuation_byte(bytes[index * N]) {
+            return Err(invalid_record::<N>(bytes, index - 1));
+        }
+        index += 1;
+    }
+    if valid_up_to < bytes.len() {
+        return Err(invalid_record::<N>(bytes, valid_up_to / N));
+    }
+    Ok(())
+}
+
+#[inline(always)]
+const fn is_continuation_byte(b: u8) -> bool {
+    b & 0xC0 == 0x80
+}
+
+/// Builds the error for the record at `index`, which is known to be invalid.
+#[cold]
+const fn invalid_record<const N: usize>(bytes: &[u8], index: usize) -> SliceError {
+    let (_, record) = bytes.split_at(index * N);
+    let (record, _) = record.split_at(N);
+    match run_utf8_validation(record) {
+        Err(error) => SliceError::InvalidRecord { index, error },
+        Ok(()) => unreachable!(),
+    }
+}
+
+/// Sorts a slice of `Str`s with a radix sort, using `scratch` as temporary storage.
+///
+/// This is a least significant digit radix sort: it does one counting pass per byte of the
+/// values and skips the bytes that are the same in every value, so it takes `O(N * len)` time
+/// and never compares strings. The order is the same as the [`Ord`] of [`Str<N>`], and since
+/// equal values are identical, the sort is stable.
+///
+/// Use [`sort_radix`] to allocate the scratch buffer instead.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than `v`.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use str_array::{slice, Str};
+/// let mut v = ["ccc", "aab", "aaa", "bab"].map(|s| Str::<3>::try_new(s).unwrap());
+/// let mut scratch = v;
+/// slice::sort_radix_with_scratch(&mut v, &mut scratch);
+/// assert_eq!(v, ["aaa", "aab", "bab", "ccc"]);
+/// ```
+pub fn sort_radix_with_scratch<const N: usize>(v: &mut [Str<N>], scratch: &mut [Str<N>]) {
+    // Below this, the counting passes cost more than a comparison sort.
+    const MIN_LEN: usize = 64;
+    assert!(
+        scratch.len() >= v.len(),
+        "scratch buffer of {} values is shorter than the slice of {}",
+        scratch.len(),
+        v.len()
+    );
+    if v.len() < MIN_LEN {
+        v.sort_unstable();
+        return;
+    }
+    let len = v.len();
+    let (mut src, mut dst) = (v, &mut scratch[..len]);
+    let mut sorted_in_scratch = false;
+    for i in (0..N).rev() {
+        let mut offsets = [0usize; 256];
+        for s in src.iter() {
+            offsets[s.v[i] as usize] += 1;
+        }
+        if offsets.contains(&len) {
+            // Every value has the same byte here, so this pass would not move anything.
+            continue;
+        }
+        let mut start = 0;
+        for offset in &mut offsets {
+            start += mem::replace(offset, start);
+        }
+        for s in src.iter() {
+            let offset = &mut offsets[s.v[i] as usize];
+            dst[*offset] = *s;
+            *offset += 1;
+        }
+        mem::swap(&mut src, &mut dst);
+        sorted_in_scratch = !sorted_in_scratch;
+    }
+    if sorted_in_scratch {
+        dst.copy_from_slice(src);
+    }
+}
+
+/// Sorts a slice of `Str`s with a radix sort, allocating a scratch buffer of the same length.
+///
+/// See [`sort_radix_with_scratch`] for how it sorts.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use str_array::{slice, Str};
+/// let mut v: Vec<Str<3>> = ["ccc", "aab", "aaa"].map(|s| Str::try_new(s).unwrap()).into();
+/// slice::sort_radix(&mut v);
+/// assert_eq!(v, ["aaa", "aab", "ccc"]);
+/// ```
+#[cfg(feature = "alloc")]
+#[inline]
+pub fn sort_radix<const N: usize>(v: &mut [Str<N>]) {
+    let mut scratch = v.to_vec();
+    sort_radix_with_scratch(v, &mut scratch);
+}
+
+/// Returns the range of indices of the values that start with `prefix`, in a sorted slice.
+///
+/// The values that start with `prefix` are contiguous in a sorted slice, so they are found with
+/// two binary searches. If the slice is not sorted, the returned range is unspecified.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use str_array::{slice, Str};
+/// let v = ["ab", "ba", "bb", "bc", "ca"].map(|s| Str::<2>::try_new(s).unwrap());
+/// assert_eq!(slice::equal_range_by_prefix(&v, "b"), 1..4);
+/// assert_eq!(slice::equal_range_by_prefix(&v, "bb"), 2..3);
+/// assert_eq!(slice::equal_range_by_prefix(&v, "bd"), 4..4);
+/// assert_eq!(slice::equal_range_by_prefix(&v, ""), 0..5);
+/// ```
+#[inline]
+#[must_use]
+pub fn equal_range_by_prefix<const N: usize>(v: &[Str<N>], prefix: &str) -> Range<usize> {
+    let start = v.partition_point(|s| s.as_str() < prefix);
+    let len = v[start..].partition_point(|s| s.starts_with(prefix));
+    start..start + len
+}
+
+/// Returns the longest prefix shared by all the values of a sorted slice.
+///
+/// In a sorted slice, that is the common prefix of the first and last values. The prefix ends
+/// on a character boundary, and is empty if the slice is empty. If the slice is not sorted, the
+/// returned prefix is unspecified.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use str_array::{slice, Str};
+/// // "è" and "é" are 2 bytes each, and only differ in their second byte.
+/// let v = ["cafè", "café"].map(|s| Str::<5>::try_new(s).unwrap());
+/// assert_eq!(slice::longest_common_prefix(&v), "caf");
+/// assert_eq!(slice::longest_common_prefix(&v[..1]), "cafè");
+/// assert_eq!(slice::longest_common_prefix::<5>(&[]), "");
+/// ```
+#[inline]
+#[must_use]
+pub fn longest_common_prefix<const N: usize>(v: &[Str<N>]) -> &str {
+    let (Some(first), Some(last)) = (v.first(), v.last()) else {
+        return "";
+    };
+    let mut len = first
+        .v
+        .iter()
+        .zip(&last.v)
+        .position(|(a, b)| a != b)
+        .unwrap_or(N);
+    // The values can differ in the middle of a character, like "é" and "è".
+    while !first.is_char_boundary(len) {
+        len -= 1;
+    }
+    &first[..len]
+}
@@ -0,0 +1,433 @@
+use crate::{InvalidLength, Str};
+use core::{char, cmp::Ordering, fmt, hash};
+
+/// The error returned when UTF-16 code units have an unpaired surrogate.
+#[derive(Copy, Eq, PartialEq, Clone, Debug)]
+pub struct Utf16Error {
+    index: usize,
+}
+
+impl Utf16Error {
+    /// Returns the index of the first code unit that is an unpaired surrogate.
+    #[inline]
+    #[must_use]
+    pub const fn valid_up_to(&self) -> usize {
+        self.index
+    }
+}
+
+impl fmt::Display for Utf16Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unpaired surrogate found at {}", self.index)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Utf16Error {}
+
+/// A stack based string of exactly `N` UTF-16 code units.
+///
+/// This is the UTF-16 sibling of [`Str<N>`], for fixed-size fields of binary formats like USB
+/// string descriptors. The code units are validated on construction, so a `Utf16Str` never holds
+/// an unpaired surrogate.
+///
+/// Like `Str<N>`, it can be compared with a `str`, orders by `char`s, and formats like a `str`.
+/// It hashes its code units though, so its hash is not the hash of the same `str`.
+///
+/// It converts to a [`Str<M>`] with [`to_str_array`](Self::to_str_array) or `TryFrom`, which
+/// fail with [`InvalidLength`] unless the UTF-8 length is exactly `M` bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use str_array::{Str, Utf16Str};
+/// let name = Utf16Str::<3>::from_le_bytes(&[b'U', 0, b'S', 0, b'B', 0]).unwrap();
+/// assert_eq!(name, *"USB");
+/// assert_eq!(name.to_be_bytes(), [0, b'U', 0, b'S', 0, b'B']);
+///
+/// let s: Str<3> = name.try_into().unwrap();
+/// assert_eq!(s, "USB");
+/// ```
+#[derive(Copy, Clone)]
+pub struct Utf16Str<const N: usize> {
+    v: [u16; N],
+}
+
+impl<const N: usize> Utf16Str<N> {
+    /// Converts an array of UTF-16 code units to a `Utf16Str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf16Error`] if the code units have an unpaired surrogate.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Utf16Str;
+    /// let heart = Utf16Str::from_utf16([0xD83D, 0xDC96]).unwrap();
+    /// assert_eq!(heart.to_string(), "💖");
+    ///
+    /// let err = Utf16Str::from_utf16([0x61, 0xDC96]).unwrap_err();
+    /// assert_eq!(err.valid_up_to(), 1);
+    /// ```
+    #[inline]
+    pub const fn from_utf16(v: [u16; N]) -> Result<Self, Utf16Error> {
+        let mut i = 0;
+        while i < N {
+            match v[i] {
+                0xD800..=0xDBFF => {
+                    if i + 1 < N && matches!(v[i + 1], 0xDC00..=0xDFFF) {
+                        i += 2;
+                        continue;
+                    }
+                    return Err(Utf16Error { index: i });
+                }
+                0xDC00..=0xDFFF => return Err(Utf16Error { index: i }),
+                _ => i += 1,
+            }
+        }
+        Ok(Self { v })
+    }
+
+    /// Converts little-endian bytes, which must be exactly `2 * N`, to a `Utf16Str`.
+    ///
+    /// Using an array of another size fails to build.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf16Error`] if the code units have an unpaired surrogate.
+    #[inline]
+    pub const fn from_le_bytes<const B: usize>(bytes: &[u8; B]) -> Result<Self, Utf16Error> {
+        const { assert!(B == 2 * N, "Utf16Str<N> requires 2 * N bytes") };
+        let mut v = [0u16; N];
+        let mut i = 0;
+        while i < N {
+            v[i] = u16::from_le_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+            i += 1;
+        }
+        Self::from_utf16(v)
+    }
+
+    /// Converts big-endian bytes, which must be exactly `2 * N`, to a `Utf16Str`.
+    ///
+    /// Using an array of another size fails to build.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Utf16Error`] if the code units have an unpaired surrogate.
+    ///
+    /// # Examples
+    ///
+    /// ```compile_fail
+    /// # use str_array::Utf16Str;
+    /// let s = Utf16Str::<3>::from_be_bytes(&[0, b'U', 0, b'S']);
+    /// ```
+    #[inline]
+    pub const fn from_be_bytes<const B: usize>(bytes: &[u8; B]) -> Result<Self, Utf16Error> {
+        const { assert!(B == 2 * N, "Utf16Str<N> requires 2 * N bytes") };
+        let mut v = [0u16; N];
+        let mut i = 0;
+        while i < N {
+            v[i] = u16::from_be_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+            i += 1;
+        }
+        Self::from_utf16(v)
+    }
+
+    /// Encodes a `&str` of exactly `N` UTF-16 code units into a new `Utf16Str`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string does not encode to exactly `N` code units.
+    #[inline]
+    pub fn try_new(s: &str) -> Result<Self, InvalidLength> {
+        let actual = s.encode_utf16().count();
+        if actual != N {
+            return Err(InvalidLength {
+                expected: N,
+                actual,
+            });
+        }
+        let mut v = [0u16; N];
+        v.iter_mut().zip(s.encode_utf16()).for_each(|(v, u)| *v = u);
+        Ok(Self { v })
+    }
+
+    /// Returns the code units, as little-endian bytes.
+    #[inline]
+    #[must_use]
+    pub const fn to_le_bytes<const B: usize>(&self) -> [u8; B] {
+        const { assert!(B == 2 * N, "Utf16Str<N> requires 2 * N bytes") };
+        let mut bytes = [0u8; B];
+        let mut i = 0;
+        while i < N {
+            let [a, b] = self.v[i].to_le_bytes();
+            bytes[2 * i] = a;
+            bytes[2 * i + 1] = b;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns the code units, as big-endian bytes.
+    #[inline]
+    #[must_use]
+    pub const fn to_be_bytes<const B: usize>(&self) -> [u8; B] {
+        const { assert!(B == 2 * N, "Utf16Str<N> requires 2 * N bytes") };
+        let mut bytes = [0u8; B];
+        let mut i = 0;
+        while i < N {
+            let [a, b] = self.v[i].to_be_bytes();
+            bytes[2 * i] = a;
+            bytes[2 * i + 1] = b;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Returns the code units.
+    #[inline]
+    #[must_use]
+    pub const fn as_utf16(&self) -> &[u16; N] {
+        &self.v
+    }
+
+    /// Converts a `Utf16Str` into its code units.
+    #[inline]
+    #[must_use = "`self` will be dropped if the result is not used"]
+    pub const fn into_utf16(self) -> [u16; N] {
+        self.v
+    }
+
+    /// Returns the length of this `Utf16Str` in code units, which is always `N`.
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if this `Utf16Str` has a length of zero, and `false` otherwise.
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns an iterator over the `char`s of this `Utf16Str`.
+    #[inline]
+    pub fn chars(&self) -> impl Iterator<Item = char> + Clone + '_ {
+        // The code units were validated on construction.
+        char::decode_utf16(self.v).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    /// Returns the length of this string once encoded as UTF-8.
+    ///
+    /// This is at most `3 * N` bytes.
+    #[inline]
+    #[must_use]
+    pub fn utf8_len(&self) -> usize {
+        self.chars().map(char::len_utf8).sum()
+    }
+
+    /// Encodes this string as UTF-8 at the start of `buf`, and returns it.
+    ///
+    /// Returns `None` if `buf` is shorter than [`utf8_len`](Self::utf8_len), which a buffer of
+    /// `3 * N` bytes never is.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Utf16Str;
+    /// let s = Utf16Str::<4>::try_new("€uro").unwrap();
+    ///
+    /// let mut buf = [0u8; 12];
+    /// assert_eq!(s.encode_utf8(&mut buf), Some("€uro"));
+    /// assert_eq!(s.encode_utf8(&mut [0u8; 5]), None);
+    /// ```
+    #[inline]
+    pub fn encode_utf8<'a>(&self, buf: &'a mut [u8]) -> Option<&'a str> {
+        let len = self.utf8_len();
+        let out = buf.get_mut(..len)?;
+        let mut i = 0;
+        for c in self.chars() {
+            c.encode_utf8(&mut out[i..]);
+            i += c.len_utf8();
+        }
+        // Safety: we only wrote whole UTF-8 encoded chars.
+        Some(unsafe { core::str::from_utf8_unchecked(out) })
+    }
+
+    /// Converts this string into a [`Str<M>`] if its UTF-8 length is exactly `M`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidLength`] if the string's UTF-8 length is not exactly `M` bytes.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::{Str, Utf16Str};
+    /// let s = Utf16Str::<4>::try_new("€uro").unwrap();
+    ///
+    /// let euro: Str<6> = s.to_str_array().unwrap();
+    /// assert_eq!(euro, "€uro");
+    ///
+    /// let err = s.to_str_array::<4>().unwrap_err();
+    /// assert_eq!(err.to_string(), "Expected string with 4 bytes, but got 6");
+    /// ```
+    #[inline]
+    pub fn to_str_array<const M: usize>(&self) -> Result<Str<M>, InvalidLength> {
+        let mut v = [0u8; M];
+        match self.encode_utf8(&mut v) {
+            Some(s) if s.len() == M => {}
+            _ => {
+                return Err(InvalidLength {
+                    expected: M,
+                    actual: self.utf8_len(),
+                })
+            }
+        }
+        // Safety: `encode_utf8` filled the array with UTF-8.
+        Ok(unsafe { Str::from_utf8_unchecked(v) })
+    }
+}
+
+impl<const N: usize> TryFrom<[u16; N]> for Utf16Str<N> {
+    type Error = Utf16Error;
+    #[inline]
+    fn try_from(v: [u16; N]) -> Result<Self, Self::Error> {
+        Self::from_utf16(v)
+    }
+}
+
+impl<const N: usize> TryFrom<&str> for Utf16Str<N> {
+    type Error = InvalidLength;
+    /// Try to encode a `&str` into a [`Utf16Str<N>`].
+    ///
+    /// The result will fail if the string does not encode to exactly `N` code units.
+    #[inline]
+    fn try_from(v: &str) -> Result<Self, Self::Error> {
+        Self::try_new(v)
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<Utf16Str<N>> for Str<M> {
+    type Error = InvalidLength;
+    /// Try to convert a [`Utf16Str<N>`] into a [`Str<M>`].
+    ///
+    /// The result will fail if the string's UTF-8 length is not exactly `M` bytes.
+    #[inline]
+    fn try_from(v: Utf16Str<N>) -> Result<Self, Self::Error> {
+        v.to_str_array()
+    }
+}
+
+impl<const N: usize, const M: usize> TryFrom<Str<M>> for Utf16Str<N> {
+    type Error = InvalidLength;
+    /// Try to convert a [`Str<M>`] into a [`Utf16Str<N>`].
+    ///
+    /// The result will fail if the string does not encode to exactly `N` code units.
+    #[inline]
+    fn try_from(v: Str<M>) -> Result<Self, Self::Error> {
+        Self::try_new(&v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> From<Utf16Str<N>> for alloc::string::String {
+    #[inline]
+    fn from(v: Utf16Str<N>) -> Self {
+        v.chars().collect()
+    }
+}
+
+impl<const N: usize> AsRef<[u16]> for Utf16Str<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u16] {
+        &self.v
+    }
+}
+
+impl<const N: usize, const M: usize> PartialEq<Utf16Str<M>> for Utf16Str<N> {
+    #[inline]
+    fn eq(&self, other: &Utf16Str<M>) -> bool {
+        self.v[..] == other.v[..]
+    }
+}
+impl<const N: usize> Eq for Utf16Str<N> {}
+
+impl<const N: usize> PartialEq<str> for Utf16Str<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.v.iter().copied().eq(other.encode_utf16())
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for Utf16Str<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl<const N: usize> PartialOrd for Utf16Str<N> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const N: usize> Ord for Utf16Str<N> {
+    /// Orders by `char`s like `str`, which is not the order of the code units for characters
+    /// above `U+FFFF`.
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.chars().cmp(other.chars())
+    }
+}
+
+impl<const N: usize> hash::Hash for Utf16Str<N> {
+    // Hashes the code units, like `Eq` compares them. `Utf16Str` does not borrow as a `str`, so
+    // nothing needs its hash to match the `str`'s.
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, hasher: &mut H) {
+        self.v.hash(hasher)
+    }
+}
+
+impl<const N: usize> fmt::Display for Utf16Str<N> {
+    /// Writes the string padded like a `str`.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use str_array::Utf16Str;
+    /// let s = Utf16Str::<3>::try_new("USB").unwrap();
+    /// assert_eq!(format!("[{s:^7}]"), "[  USB  ]");
+    /// assert_eq!(format!("[{s:-<5.2}]"), "[US---]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::pad_chars(f, self.chars())
+    }
+}
+
+impl<const N: usize> fmt::Debug for Utf16Str<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+
+        f.write_char('"')?;
+        for c in self.chars() {
+            crate::write_debug_chars(c.encode_utf8(&mut [0; 4]), |s| f.write_str(s))?;
+        }
+        f.write_char('"')
+    }
+}
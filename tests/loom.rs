@@ -0,0 +1,70 @@
+//! Model checks of `AtomicStr`, run with:
+//!
+//! ```sh
+//! RUSTFLAGS="--cfg loom" cargo test --release --test loom
+//! ```
+#![cfg(loom)]
+
+use loom::{model::Builder, sync::Arc, thread};
+use str_array::{AtomicStr, Str};
+
+fn model<F: Fn() + Sync + Send + 'static>(f: F) {
+    // The seqlock's bytes are separate atomics, so exploring every interleaving of them is out
+    // of reach; a few preemptions are enough to land a read in the middle of a write.
+    let mut builder = Builder::new();
+    builder.preemption_bound = Some(3);
+    builder.check(f);
+}
+
+fn check_no_torn_reads<const N: usize>(a: &str, b: &str) {
+    let a: Str<N> = Str::try_new(a).unwrap();
+    let b: Str<N> = Str::try_new(b).unwrap();
+    model(move || {
+        let cell = Arc::new(AtomicStr::new(a));
+
+        let writer = {
+            let cell = Arc::clone(&cell);
+            thread::spawn(move || cell.store(b))
+        };
+
+        let s = cell.load();
+        assert!(s == a || s == b, "torn read: {:?}", s);
+
+        writer.join().unwrap();
+        assert_eq!(cell.load(), b);
+    });
+}
+
+#[test]
+fn native_load_store() {
+    check_no_torn_reads::<8>("aaaaaaaa", "bbbbbbbb");
+}
+
+#[test]
+fn seqlock_load_store() {
+    check_no_torn_reads::<9>("aaaaaaaaa", "bbbbbbbbb");
+}
+
+#[test]
+fn seqlock_concurrent_compare_exchange() {
+    let idle: Str<9> = Str::try_new("idle.....").unwrap();
+    let busy: Str<9> = Str::try_new("busy.....").unwrap();
+    model(move || {
+        let cell = Arc::new(AtomicStr::new(idle));
+
+        let threads: Vec<_> = (0..2)
+            .map(|_| {
+                let cell = Arc::clone(&cell);
+                thread::spawn(move || cell.compare_exchange(idle, busy).is_ok())
+            })
+            .collect();
+        let winners = threads
+            .into_iter()
+            .map(|t| t.join().unwrap())
+            .filter(|&won| won)
+            .count();
+
+        assert_eq!(winners, 1);
+        assert_eq!(cell.load(), busy);
+    });
+}